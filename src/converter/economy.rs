@@ -0,0 +1,135 @@
+/*!
+This module defines how a match economy page is parsed.
+*/
+use crate::data::*;
+use crate::tl_extensions::*;
+use crate::ConvertInstance;
+use crate::{Error, Error::ConversionError};
+
+impl ConvertInstance for EconomyPage {
+    fn convert<'a>(d: &'a tl::VDom<'a>) -> Result<EconomyPage, Error> {
+        let root = d
+            .query_selector("div.stats-match")
+            .unwrap()
+            .next()
+            .ok_or(ConversionError("no stats-match node found"))?
+            .to_rich(d);
+        Ok(EconomyPage {
+            id: get_id(d)?,
+            rounds: get_rounds(root)?,
+        })
+    }
+}
+
+/// Returns the map stats ID.
+fn get_id(d: &tl::VDom) -> Result<u32, Error> {
+    for elem in d.query_selector("link[href]").unwrap() {
+        let link = elem.to_rich(d).get_attr_str("href").unwrap();
+        if link.contains("https://www.hltv.org/stats/matches/economy/mapstatsid/") {
+            let chunk = link
+                .split('/')
+                .nth(7)
+                .ok_or(ConversionError("error parsing economy link tag"))?;
+            return chunk.parse().map_err(|_| Error::ParseError);
+        }
+    }
+    Err(ConversionError("couldn't find link tag with map stats ID"))
+}
+
+/// Returns the economy of all rounds. HLTV splits the rounds into several tables
+/// (one per half and overtime), each with one row per team.
+fn get_rounds(h: RichNode) -> Result<Vec<EconomyRound>, Error> {
+    let mut result = Vec::new();
+    for table in h.find_all("equipment-categories") {
+        let rows = table.find_all("team-categories");
+        if rows.len() != 2 {
+            return Err(ConversionError("economy table doesn't have two team rows"));
+        }
+        let team1 = rows[0].find_all("equipment-category-td");
+        let team2 = rows[1].find_all("equipment-category-td");
+        for (t1, t2) in team1.into_iter().zip(team2) {
+            result.push(EconomyRound {
+                number: result.len() as u32 + 1,
+                team1: get_team_economy(t1)?,
+                team2: get_team_economy(t2)?,
+            });
+        }
+    }
+    Ok(result)
+}
+
+/// Parses the economy of one team in one round. The buy type and the round outcome
+/// are encoded in the icon name, e.g. `forcebuyWin.svg`.
+fn get_team_economy(h: RichNode) -> Result<TeamEconomy, Error> {
+    let equipment_value = h
+        .get_attr_str("title")
+        .ok_or(ConversionError("no equipment value found"))?
+        .split(':')
+        .nth(1)
+        .and_then(|v| v.trim().parse().ok())
+        .ok_or(ConversionError("equipment value has incorrect format"))?;
+    let icon = h
+        .find("equipment-category")
+        .get_attr_str("src")
+        .ok_or(ConversionError("no buy type icon found"))?
+        .rsplit('/')
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+    let buy_type = if icon.starts_with("pistol") {
+        BuyType::Pistol
+    } else if icon.starts_with("forcebuy") {
+        BuyType::ForceBuy
+    } else if icon.starts_with("fullbuy") {
+        BuyType::FullBuy
+    } else if icon.starts_with("eco") {
+        BuyType::Eco
+    } else {
+        BuyType::Unknown
+    };
+    Ok(TeamEconomy {
+        equipment_value,
+        buy_type,
+        won: icon.contains("win"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    /// Tests if the economy of both halves is parsed in the correct order.
+    #[test]
+    pub fn economy() {
+        let input = include_str!("../testdata/economyPages/inferno.html");
+        let dom = tl::parse(input, tl::ParserOptions::default()).unwrap();
+        let result = EconomyPage::convert(&dom).unwrap();
+        assert_eq!(result.id, 114121);
+        assert_eq!(result.rounds.len(), 5);
+        assert_eq!(
+            result.rounds[1],
+            EconomyRound {
+                number: 2,
+                team1: TeamEconomy {
+                    equipment_value: 18300,
+                    buy_type: BuyType::ForceBuy,
+                    won: false,
+                },
+                team2: TeamEconomy {
+                    equipment_value: 21400,
+                    buy_type: BuyType::FullBuy,
+                    won: true,
+                },
+            }
+        );
+        assert_eq!(result.rounds[2].team2.buy_type, BuyType::Eco);
+        assert_eq!(result.rounds[3].number, 4);
+        assert_eq!(result.rounds[3].team1.buy_type, BuyType::Pistol);
+        assert!(result.rounds[3].team2.won);
+        // unrecognized icons don't fail the page
+        assert_eq!(result.rounds[4].team1.buy_type, BuyType::Unknown);
+        assert!(result.rounds[4].team1.won);
+        assert_eq!(result.rounds[4].team2.buy_type, BuyType::ForceBuy);
+    }
+}
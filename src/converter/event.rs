@@ -0,0 +1,338 @@
+/*!
+This module defines how the details of an event are parsed.
+*/
+use chrono::{DateTime, TimeZone, Utc};
+
+use crate::data::*;
+use crate::tl_extensions::*;
+use crate::ConvertInstance;
+use crate::{Error, Error::ConversionError};
+
+impl ConvertInstance for EventDetails {
+    fn convert<'a>(d: &'a tl::VDom<'a>) -> Result<EventDetails, Error> {
+        let root = d
+            .query_selector("div.event-hub-top")
+            .unwrap()
+            .next()
+            .ok_or(ConversionError("no event-hub-top node found"))?
+            .to_rich(d);
+        let info = root.find("info");
+        let (start_date, end_date) = get_dates(info)?;
+        let price_pool = info
+            .find("prizepool")
+            .inner_text()
+            .map(|p| p.trim().to_string())
+            .unwrap_or_default();
+        let location = info
+            .find("location")
+            .inner_text()
+            .map(|l| l.trim().to_string())
+            .filter(|l| !l.is_empty());
+        Ok(EventDetails {
+            id: get_id(d)?,
+            name: root
+                .find("event-hub-title")
+                .inner_text()
+                .map(|n| n.trim().to_string())
+                .ok_or(ConversionError("no event name found"))?,
+            start_date,
+            end_date,
+            prize: parse_prize(&price_pool),
+            price_pool,
+            event_type: parse_event_type(location.as_deref()),
+            location,
+            teams: info
+                .find("teamsNumber")
+                .inner_text()
+                .and_then(|t| t.trim().parse().ok()),
+            stages: get_stages(d),
+        })
+    }
+}
+
+/// Derives the event type from a location like `Europe (Online)`. Any other
+/// location is a venue, so the event is played on LAN. `None` if no location is listed.
+pub(crate) fn parse_event_type(location: Option<&str>) -> Option<EventType> {
+    match location?.contains("Online") {
+        true => Some(EventType::Online),
+        false => Some(EventType::LAN),
+    }
+}
+
+/// Returns all stages of the event.
+fn get_stages(d: &tl::VDom) -> Vec<Stage> {
+    let root = match d.query_selector("div.event-stages").unwrap().next() {
+        Some(x) => x.to_rich(d),
+        None => return Vec::new(),
+    };
+    root.find_all("event-stage")
+        .into_iter()
+        .map(|h| {
+            let kind = match h.get_attr_str("data-stage-type").as_deref() {
+                Some("swiss") => StageKind::Swiss,
+                Some("bracket") => StageKind::Bracket,
+                _ => StageKind::Groups,
+            };
+            Stage {
+                name: h
+                    .find("stage-name")
+                    .inner_text()
+                    .map(|n| n.trim().to_string())
+                    .unwrap_or_default(),
+                kind,
+                groups: h.find_all("group").into_iter().map(get_group).collect(),
+                slots: get_slots(h),
+            }
+        })
+        .collect()
+}
+
+fn get_group(h: RichNode) -> Group {
+    Group {
+        name: h
+            .find("group-name")
+            .inner_text()
+            .map(|n| n.trim().to_string())
+            .unwrap_or_default(),
+        standings: h
+            .find_all("group-row")
+            .into_iter()
+            .filter_map(get_standing)
+            .collect(),
+    }
+}
+
+/// Parses a row of a group table. The record is listed as `W-L`.
+fn get_standing(h: RichNode) -> Option<GroupStanding> {
+    let record = h.find("group-record").inner_text()?;
+    let (wins, losses) = record.trim().split_once('-')?;
+    Some(GroupStanding {
+        position: h
+            .find("group-place")
+            .inner_text()?
+            .trim()
+            .trim_end_matches('.')
+            .parse()
+            .ok()?,
+        team: get_team_link(h.find("group-team"))?,
+        wins: wins.parse().ok()?,
+        losses: losses.parse().ok()?,
+        round_diff: h
+            .find("group-round-diff")
+            .inner_text()?
+            .trim()
+            .trim_start_matches('+')
+            .parse()
+            .ok()?,
+    })
+}
+
+/// Returns all bracket slots of a stage.
+fn get_slots(h: RichNode) -> Vec<BracketSlot> {
+    let mut result = Vec::new();
+    for round in h.find_all("bracket-round") {
+        let name = round
+            .find("round-header")
+            .inner_text()
+            .map(|n| n.trim().to_string())
+            .unwrap_or_default();
+        for slot in round.find_all("slot") {
+            let team1 = slot.find("team1");
+            let team2 = slot.find("team2");
+            let score1 = team1.find("slot-score").inner_parse().ok().flatten();
+            let score2 = team2.find("slot-score").inner_parse().ok().flatten();
+            let winner = if team1.has_class("winner").unwrap_or(false) {
+                WhichTeam::First
+            } else if team2.has_class("winner").unwrap_or(false) {
+                WhichTeam::Second
+            } else {
+                WhichTeam::None
+            };
+            result.push(BracketSlot {
+                round: name.clone(),
+                match_id: slot
+                    .find("match-link")
+                    .get_attr_str("href")
+                    .and_then(|x| x.split('/').nth(2)?.parse().ok()),
+                team1: get_team_link(team1),
+                team2: get_team_link(team2),
+                score: match (score1, score2) {
+                    (Some(team1), Some(team2)) => Some(MatchScore { team1, team2 }),
+                    _ => None,
+                },
+                winner,
+            });
+        }
+    }
+    result
+}
+
+/// Returns the team linked within the given node. Undetermined teams aren't linked.
+fn get_team_link(h: RichNode) -> Option<TeamRef> {
+    let link = h.find_tag("a");
+    Some(TeamRef {
+        id: link.get_attr_str("href")?.split('/').nth(2)?.parse().ok()?,
+        name: link.inner_text()?.trim().to_string(),
+    })
+}
+
+/// Returns the event ID from the canonical link of the page.
+fn get_id(d: &tl::VDom) -> Result<u32, Error> {
+    for elem in d.query_selector("link[href]").unwrap() {
+        let link = elem.to_rich(d).get_attr_str("href").unwrap();
+        if link.contains("https://www.hltv.org/events/") {
+            let chunk = link
+                .split('/')
+                .nth(4)
+                .ok_or(ConversionError("error parsing event link tag"))?;
+            return chunk.parse().map_err(|_| Error::ParseError);
+        }
+    }
+    Err(ConversionError("couldn't find link tag with event ID"))
+}
+
+/// Returns start and end date. Single-day events only list one date.
+fn get_dates(h: RichNode) -> Result<(DateTime<Utc>, DateTime<Utc>), Error> {
+    let cell = h.find("eventdate");
+    let start = cell.find_where(|n| n.get_attr_str("data-unix").is_some());
+    let end = cell
+        .child(1)
+        .map(|c| c.find_where(|n| n.get_attr_str("data-unix").is_some()));
+    let start = parse_unix(start)?;
+    let end = match end {
+        Some(e) => parse_unix(e)?,
+        None => start,
+    };
+    Ok((start, end))
+}
+
+fn parse_unix(h: RichNode) -> Result<DateTime<Utc>, Error> {
+    let timestamp: i64 = h
+        .get_attr("data-unix")?
+        .ok_or(ConversionError("no data-unix attribute"))?;
+    Utc.timestamp_opt(timestamp / 1000, 0)
+        .single()
+        .ok_or(ConversionError(
+            "data-unix attribute is not a valid timestamp",
+        ))
+}
+
+/// Parses the USD figure at the start of a prize pool like `$250,000 and spots in ...`.
+pub(crate) fn parse_prize(s: &str) -> Option<u32> {
+    let amount: String = s
+        .trim()
+        .strip_prefix('$')?
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == ',')
+        .filter(|c| *c != ',')
+        .collect();
+    amount.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    /// Tests if a LAN event with all details is parsed.
+    #[test]
+    pub fn lan() {
+        let input = include_str!("../testdata/eventPages/major.html");
+        let dom = tl::parse(input, tl::ParserOptions::default()).unwrap();
+        let result = EventDetails::convert(&dom).unwrap();
+        assert_eq!(
+            result,
+            EventDetails {
+                id: 6372,
+                name: "PGL Major Antwerp 2022".to_string(),
+                start_date: Utc.timestamp_opt(1652486400, 0).unwrap(),
+                end_date: Utc.timestamp_opt(1653177600, 0).unwrap(),
+                price_pool: "$1,000,000".to_string(),
+                prize: Some(1000000),
+                location: Some("Antwerp, Belgium".to_string()),
+                event_type: Some(EventType::LAN),
+                teams: Some(24),
+                stages: vec![],
+            }
+        );
+    }
+
+    /// Tests if group tables, Swiss records and bracket slots are parsed.
+    #[test]
+    pub fn stages() {
+        let input = include_str!("../testdata/eventPages/stages.html");
+        let dom = tl::parse(input, tl::ParserOptions::default()).unwrap();
+        let result = EventDetails::convert(&dom).unwrap();
+        assert_eq!(result.stages.len(), 3);
+        let swiss = &result.stages[0];
+        assert_eq!(swiss.name, "Legends Stage");
+        assert_eq!(swiss.kind, StageKind::Swiss);
+        assert_eq!(swiss.groups.len(), 1);
+        assert_eq!(
+            swiss.groups[0].standings[1],
+            GroupStanding {
+                position: 2,
+                team: TeamRef {
+                    id: 4608,
+                    name: "Natus Vincere".to_string()
+                },
+                wins: 3,
+                losses: 1,
+                round_diff: 18,
+            }
+        );
+        assert_eq!(swiss.groups[0].standings[2].round_diff, -22);
+        let groups = &result.stages[1];
+        assert_eq!(groups.kind, StageKind::Groups);
+        assert_eq!(groups.groups.len(), 2);
+        assert_eq!(groups.groups[1].name, "Group B");
+        assert_eq!(groups.groups[1].standings[0].round_diff, 0);
+        assert!(groups.slots.is_empty());
+        let playoffs = &result.stages[2];
+        assert_eq!(playoffs.kind, StageKind::Bracket);
+        assert_eq!(
+            playoffs.slots[0],
+            BracketSlot {
+                round: "Semi-finals".to_string(),
+                match_id: Some(2356200),
+                team1: Some(TeamRef {
+                    id: 6667,
+                    name: "FaZe".to_string()
+                }),
+                team2: Some(TeamRef {
+                    id: 4608,
+                    name: "Natus Vincere".to_string()
+                }),
+                score: Some(MatchScore { team1: 2, team2: 1 }),
+                winner: WhichTeam::First,
+            }
+        );
+        assert_eq!(playoffs.slots[1].round, "Grand final");
+        assert_eq!(playoffs.slots[1].match_id, None);
+        assert_eq!(playoffs.slots[1].team2, None);
+        assert_eq!(playoffs.slots[1].score, None);
+    }
+
+    /// Tests if online events and prize pools with additional spots are parsed.
+    #[test]
+    pub fn online() {
+        let input = include_str!("../testdata/eventPages/online.html");
+        let dom = tl::parse(input, tl::ParserOptions::default()).unwrap();
+        let result = EventDetails::convert(&dom).unwrap();
+        assert_eq!(result.id, 5206);
+        assert_eq!(result.event_type, Some(EventType::Online));
+        assert_eq!(result.prize, Some(250000));
+        assert_eq!(result.teams, None);
+    }
+
+    /// Tests that the event type is unknown if no location is listed.
+    #[test]
+    pub fn no_location() {
+        let input =
+            include_str!("../testdata/eventPages/online.html").replace("Europe (Online)", "");
+        let dom = tl::parse(&input, tl::ParserOptions::default()).unwrap();
+        let result = EventDetails::convert(&dom).unwrap();
+        assert_eq!(result.location, None);
+        assert_eq!(result.event_type, None);
+    }
+}
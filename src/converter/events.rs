@@ -0,0 +1,123 @@
+/*!
+This module defines how event listings (events page and archive) are parsed.
+*/
+use chrono::{DateTime, TimeZone, Utc};
+
+use crate::converter::event::{parse_event_type, parse_prize};
+use crate::data::*;
+use crate::tl_extensions::*;
+use crate::ConvertInstance;
+use crate::{Error, Error::ConversionError};
+
+impl ConvertInstance for Vec<EventSummary> {
+    fn convert<'a>(d: &'a tl::VDom<'a>) -> Result<Vec<EventSummary>, Error> {
+        let root = d
+            .query_selector("div.events-page")
+            .unwrap()
+            .next()
+            .ok_or(ConversionError("no div.events-page container found"))?
+            .to_rich(d);
+        // big and small events are interleaved, so they are collected in one pass
+        // to keep the order of the page
+        root.find_all_where(|n| {
+            ["ongoing-event", "big-event", "small-event"]
+                .into_iter()
+                .any(|c| n.has_class(c) == Some(true))
+        })
+        .into_iter()
+        .map(|h| get_event(h, h.has_class("ongoing-event") == Some(true)))
+        .collect()
+    }
+}
+
+/// Parses a single event box. Ongoing events only show name and dates.
+fn get_event(h: RichNode, ongoing: bool) -> Result<EventSummary, Error> {
+    let details: Vec<String> = h
+        .find_all("small-col")
+        .into_iter()
+        .map(|x| x.inner_text().unwrap_or_default().trim().to_string())
+        .collect();
+    let (start_date, end_date) = get_dates(h.find("col-date"));
+    let location = h
+        .find("smallCountry")
+        .inner_text()
+        .map(|l| l.trim().to_string())
+        .filter(|l| !l.is_empty());
+    let event_type = match details.get(2).map(String::as_str) {
+        Some("Online") => Some(EventType::Online),
+        Some(t) if t.contains("LAN") => Some(EventType::LAN),
+        _ => parse_event_type(location.as_deref()),
+    };
+    Ok(EventSummary {
+        id: h
+            .get_attr_str("href")
+            .and_then(|x| x.split('/').nth(2)?.parse().ok())
+            .ok_or(ConversionError("couldn't parse event ID"))?,
+        name: h
+            .find("text-ellipsis")
+            .inner_text()
+            .map(|n| n.trim().to_string())
+            .ok_or(ConversionError("no event name found"))?,
+        start_date,
+        end_date,
+        prize: details.get(1).and_then(|p| parse_prize(p)),
+        teams: details.first().and_then(|t| t.parse().ok()),
+        location,
+        event_type,
+        ongoing,
+    })
+}
+
+/// Returns start and end date. Single-day events only list one date.
+fn get_dates(h: RichNode) -> (Option<DateTime<Utc>>, Option<DateTime<Utc>>) {
+    let parse = |i| -> Option<DateTime<Utc>> {
+        let timestamp: i64 = h.child(i)?.get_attr("data-unix").ok()??;
+        Utc.timestamp_opt(timestamp / 1000, 0).single()
+    };
+    let start = parse(0);
+    (start, parse(1).or(start))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    /// Tests if ongoing and upcoming events are parsed.
+    #[test]
+    pub fn events() {
+        let input = include_str!("../testdata/eventListPages/events.html");
+        let dom = tl::parse(input, tl::ParserOptions::default()).unwrap();
+        let result = Vec::<EventSummary>::convert(&dom).unwrap();
+        assert_eq!(result.len(), 4);
+        assert!(result[0].ongoing);
+        assert_eq!(result[0].id, 6372);
+        assert_eq!(result[0].prize, None);
+        assert_eq!(result[0].event_type, None);
+        assert_eq!(
+            result[0].end_date,
+            Some(Utc.timestamp_opt(1653177600, 0).unwrap())
+        );
+        assert_eq!(
+            result[1],
+            EventSummary {
+                id: 6503,
+                name: "IEM Cologne 2022".to_string(),
+                start_date: Some(Utc.timestamp_opt(1657065600, 0).unwrap()),
+                end_date: Some(Utc.timestamp_opt(1658016000, 0).unwrap()),
+                prize: Some(1000000),
+                teams: Some(24),
+                location: Some("Cologne, Germany".to_string()),
+                event_type: Some(EventType::LAN),
+                ongoing: false,
+            }
+        );
+        // the big event is listed between the small events
+        assert_eq!(result[2].id, 6514);
+        assert_eq!(result[2].name, "BLAST Premier Fall Groups 2022");
+        assert_eq!(result[2].prize, Some(177500));
+        assert_eq!(result[3].event_type, Some(EventType::Online));
+        assert_eq!(result[3].teams, None);
+        assert_eq!(result[3].start_date, result[3].end_date);
+    }
+}
@@ -0,0 +1,122 @@
+/*!
+This module defines how the player leaderboard of the stats section is parsed.
+*/
+use crate::data::*;
+use crate::tl_extensions::*;
+use crate::ConvertInstance;
+use crate::{Error, Error::ConversionError};
+
+impl ConvertInstance for Vec<LeaderboardEntry> {
+    fn convert<'a>(d: &'a tl::VDom<'a>) -> Result<Vec<LeaderboardEntry>, Error> {
+        let body = d
+            .query_selector("table.player-ratings-table")
+            .unwrap()
+            .next()
+            .ok_or(ConversionError("no player-ratings-table found"))?
+            .to_rich(d)
+            .find_tag("tbody");
+        let mut result = Vec::new();
+        let mut i = 0;
+        while let Some(row) = body.child(i) {
+            i += 1;
+            result.push(get_entry(row)?);
+        }
+        Ok(result)
+    }
+}
+
+/// Parses a single row of the leaderboard.
+fn get_entry(row: RichNode) -> Result<LeaderboardEntry, Error> {
+    let player = row.find("playerCol");
+    let link = player.find_tag("a");
+    let details: Vec<String> = row
+        .find_all("statsDetail")
+        .into_iter()
+        .filter_map(|x| x.inner_text())
+        .map(|x| x.trim().to_string())
+        .collect();
+    if details.len() != 3 {
+        return Err(ConversionError("leaderboard row has incorrect format"));
+    }
+    Ok(LeaderboardEntry {
+        player: Player {
+            id: link
+                .get_attr_str("href")
+                .and_then(|x| x.split('/').nth(3)?.parse().ok())
+                .ok_or(ConversionError("couldn't parse leaderboard player ID"))?,
+            nickname: link
+                .inner_text()
+                .ok_or(ConversionError("no leaderboard player name found"))?,
+        },
+        country: player.find("flag").get_attr_str("title"),
+        teams: get_teams(row.find("teamCol")),
+        maps_played: details[0].parse().map_err(|_| Error::ParseError)?,
+        rounds_played: details[1].parse().map_err(|_| Error::ParseError)?,
+        kd_diff: row
+            .find("kdDiffCol")
+            .inner_text()
+            .and_then(|x| x.trim().trim_start_matches('+').parse().ok())
+            .ok_or(ConversionError("couldn't parse K-D diff"))?,
+        kd_ratio: details[2].parse().map_err(|_| Error::ParseError)?,
+        rating: row
+            .find("ratingCol")
+            .inner_parse()?
+            .ok_or(ConversionError("no rating found"))?,
+    })
+}
+
+/// Returns the teams of a row, which are only shown as logos.
+fn get_teams(h: RichNode) -> Vec<TeamRef> {
+    let mut result = Vec::new();
+    let mut i = 0;
+    while let Some(link) = h.child(i) {
+        i += 1;
+        let team = || {
+            Some(TeamRef {
+                id: link.get_attr_str("href")?.split('/').nth(3)?.parse().ok()?,
+                name: link.find_tag("img").get_attr_str("title")?,
+            })
+        };
+        if let Some(team) = team() {
+            result.push(team);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    /// Tests if leaderboard rows are parsed, including players with multiple teams.
+    #[test]
+    pub fn players() {
+        let input = include_str!("../testdata/leaderboardPages/players.html");
+        let dom = tl::parse(input, tl::ParserOptions::default()).unwrap();
+        let result = Vec::<LeaderboardEntry>::convert(&dom).unwrap();
+        assert_eq!(result.len(), 3);
+        assert_eq!(
+            result[0],
+            LeaderboardEntry {
+                player: Player {
+                    id: 7998,
+                    nickname: "s1mple".to_string()
+                },
+                country: Some("Ukraine".to_string()),
+                teams: vec![TeamRef {
+                    id: 4608,
+                    name: "Natus Vincere".to_string()
+                }],
+                maps_played: 211,
+                rounds_played: 5521,
+                kd_diff: 1342,
+                kd_ratio: 1.44,
+                rating: 1.32,
+            }
+        );
+        assert_eq!(result[2].teams.len(), 2);
+        assert_eq!(result[2].teams[1].name, "BIG");
+        assert_eq!(result[2].kd_diff, -25);
+    }
+}
@@ -0,0 +1,315 @@
+/*!
+This module defines how a map stats page is parsed.
+*/
+use chrono::DateTime;
+use chrono::TimeZone;
+use chrono::Utc;
+use tl::NodeHandle;
+
+use crate::data::*;
+use crate::tl_extensions::*;
+use crate::ConvertInstance;
+use crate::{Error, Error::ConversionError};
+
+impl ConvertInstance for MapStatsPage {
+    fn convert<'a>(d: &'a tl::VDom<'a>) -> Result<MapStatsPage, Error> {
+        let root = get_root(d)?.to_rich(d);
+        let id = get_id(d)?;
+        Ok(MapStatsPage {
+            id,
+            match_id: get_match_id(root),
+            map: get_map(root, id),
+            date: get_date(root)?,
+            event: get_event(root)?,
+            team1: get_team(root, "team-left"),
+            team2: get_team(root, "team-right"),
+            score: get_score(root)?,
+            rounds: get_rounds(root),
+            stats: get_performance(root),
+        })
+    }
+}
+
+/// Returns the relevant root node.
+fn get_root(d: &tl::VDom) -> Result<NodeHandle, Error> {
+    d.query_selector("div.stats-match")
+        .unwrap()
+        .next()
+        .ok_or(ConversionError("no stats-match node found"))
+}
+
+/// Returns the map stats ID.
+fn get_id(d: &tl::VDom) -> Result<u32, Error> {
+    for elem in d.query_selector("link[href]").unwrap() {
+        let link = elem.to_rich(d).get_attr_str("href").unwrap();
+        if link.contains("https://www.hltv.org/stats/matches/mapstatsid/") {
+            let chunk = link
+                .split('/')
+                .nth(6)
+                .ok_or(ConversionError("error parsing map stats link tag"))?;
+            return chunk.parse().map_err(|_| Error::ParseError);
+        }
+    }
+    Err(ConversionError("couldn't find link tag with map stats ID"))
+}
+
+/// Returns the ID of the match page this map belongs to.
+fn get_match_id(h: RichNode) -> Option<u32> {
+    h.find("match-page-link")
+        .get_attr_str("href")?
+        .split('/')
+        .nth(2)?
+        .parse()
+        .ok()
+}
+
+/// Returns the map name of the selected map tab.
+fn get_map(h: RichNode, id: u32) -> Map {
+    let needle = format!("/mapstatsid/{}/", id);
+    h.find_all("stats-match-map")
+        .into_iter()
+        .find(|m| m.get_attr_str("href").is_some_and(|l| l.contains(&needle)))
+        .and_then(|m| m.find("dynamic-map-name-full").inner_text())
+        .map(Map::from)
+        .unwrap_or_default()
+}
+
+/// Returns the time the map was played.
+fn get_date(h: RichNode) -> Result<DateTime<Utc>, Error> {
+    let timestamp: i64 = h
+        .find("match-info-box")
+        .find("small-text")
+        .find_tag("span")
+        .get_attr("data-unix")?
+        .ok_or(ConversionError("no data-unix attribute"))?;
+    Utc.timestamp_opt(timestamp / 1000, 0)
+        .single()
+        .ok_or(ConversionError(
+            "data-unix attribute is not a valid timestamp",
+        ))
+}
+
+/// Returns the event the map was played at.
+fn get_event(h: RichNode) -> Result<Event, Error> {
+    let event = h.find("match-info-box").find_where(|n| {
+        n.get_attr_str("href")
+            .is_some_and(|l| l.starts_with("/events/"))
+    });
+    let id = event
+        .get_attr_str("href")
+        .ok_or(ConversionError("no event link found"))?
+        .split('/')
+        .nth(2)
+        .ok_or(ConversionError("event link has incorrect format"))?
+        .parse()
+        .map_err(|_| ConversionError("cant parse event ID"))?;
+    Ok(Event {
+        id,
+        name: event
+            .inner_text()
+            .ok_or(ConversionError("event link has no name"))?,
+    })
+}
+
+/// Returns the team of the given side of the info box (`team-left` or `team-right`).
+fn get_team(h: RichNode, class: &str) -> Option<Team> {
+    let t = h.find("match-info-box").find(class);
+    let link = t.find_tag("a");
+    Some(Team {
+        id: link.get_attr_str("href")?.split('/').nth(3)?.parse().ok()?,
+        name: link.inner_text()?,
+        logo: t.find("team-logo").get_attr_str_esc("src")?,
+        alt_logo: None,
+    })
+}
+
+/// Returns the final round score of the map.
+fn get_score(h: RichNode) -> Result<RoundScore, Error> {
+    let score = |class| {
+        h.find("match-info-box")
+            .find(class)
+            .find("bold")
+            .inner_parse()?
+            .ok_or(ConversionError("no map score found"))
+    };
+    Ok(RoundScore {
+        team1: score("team-left")?,
+        team2: score("team-right")?,
+    })
+}
+
+/// Returns the round history. Each history block has one row per team, where the
+/// winning team of a round has an outcome icon and the other an empty one.
+fn get_rounds(h: RichNode) -> Vec<Round> {
+    let mut result = Vec::new();
+    for block in h.find_all("round-history-con") {
+        let rows = block.find_all("round-history-team-row");
+        if rows.len() != 2 {
+            continue;
+        }
+        let team1 = rows[0].find_all("round-history-outcome");
+        let team2 = rows[1].find_all("round-history-outcome");
+        for (r1, r2) in team1.into_iter().zip(team2) {
+            let (winner, icon) = match (get_round_outcome(r1), get_round_outcome(r2)) {
+                (Some(o), None) => (WhichTeam::First, o),
+                (None, Some(o)) => (WhichTeam::Second, o),
+                // round wasn't played
+                _ => continue,
+            };
+            let (side, win_type, score) = icon;
+            result.push(Round {
+                number: result.len() as u32 + 1,
+                winner,
+                side,
+                win_type,
+                score,
+            });
+        }
+    }
+    result
+}
+
+/// Parses a round outcome icon. Returns `None` for empty icons.
+fn get_round_outcome(h: RichNode) -> Option<(Side, RoundWinType, RoundScore)> {
+    let src = h.get_attr_str("src")?;
+    let icon = src.rsplit('/').next()?;
+    let (side, win_type) = match icon {
+        "ct_win.svg" => (Side::CT, RoundWinType::Elimination),
+        "t_win.svg" => (Side::T, RoundWinType::Elimination),
+        "bomb_exploded.svg" => (Side::T, RoundWinType::BombExploded),
+        "bomb_defused.svg" => (Side::CT, RoundWinType::BombDefused),
+        "stopwatch.svg" => (Side::CT, RoundWinType::TimeRanOut),
+        _ => return None,
+    };
+    let title = h.get_attr_str("title")?;
+    let mut score = title.split('-').map(|x| x.trim().parse::<u32>());
+    let score = RoundScore {
+        team1: score.next()?.ok()?,
+        team2: score.next()?.ok()?,
+    };
+    Some((side, win_type, score))
+}
+
+/// Returns the performance of all players. Players of the first team come first.
+fn get_performance(h: RichNode) -> Vec<DetailedPerformance> {
+    let mut result = Vec::new();
+    for table in h.find_all("totalstats") {
+        let body = table.find_tag("tbody");
+        let mut i = 0;
+        while let Some(row) = body.child(i) {
+            if let Some(perf) = get_performance_player(row) {
+                result.push(perf);
+            }
+            i += 1;
+        }
+    }
+    result
+}
+
+/// Get the performance of a specific player in a table row.
+fn get_performance_player(h: RichNode) -> Option<DetailedPerformance> {
+    let link = h.find("st-player").find_tag("a");
+    let p = Player {
+        id: link.get_attr_str("href")?.split('/').nth(3)?.parse().ok()?,
+        nickname: link.inner_text()?,
+    };
+    let (kills, headshots) = parse_with_bracket(&h.find("st-kills").inner_text()?)?;
+    let (assists, flash_assists) = parse_with_bracket(&h.find("st-assists").inner_text()?)?;
+    let kast = h.find("st-kdratio").inner_text()?;
+    let s = DetailedStats {
+        kills,
+        headshots,
+        assists,
+        flash_assists,
+        deaths: h.find("st-deaths").inner_text()?.trim().parse().ok()?,
+        kast: kast.split('%').next()?.trim().parse().ok()?,
+        adr: h.find("st-adr").inner_text()?.trim().parse().ok()?,
+        first_kills_diff: h.find("st-fkdiff").inner_text()?.trim().parse().ok()?,
+        rating: h.find("st-rating").inner_text()?.trim().parse().ok()?,
+    };
+    Some(DetailedPerformance(p, s))
+}
+
+/// Parses values of the form `23 (11)`. The bracketed value is optional and
+/// defaults to zero.
+fn parse_with_bracket(s: &str) -> Option<(u32, u32)> {
+    let mut it = s.split('(');
+    let first = it.next()?.trim().parse().ok()?;
+    let second = match it.next() {
+        Some(x) => x.trim_end_matches(')').trim().parse().ok()?,
+        None => 0,
+    };
+    Some((first, second))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    /// Tests if a played map with round history and player stats is parsed correctly.
+    #[test]
+    pub fn finished_map() {
+        let input = include_str!("../testdata/mapStatsPages/inferno.html");
+        let dom = tl::parse(input, tl::ParserOptions::default()).unwrap();
+        let result = MapStatsPage::convert(&dom).unwrap();
+        assert_eq!(result.id, 114121);
+        assert_eq!(result.match_id, Some(2346065));
+        assert_eq!(result.map, Map::Inferno);
+        assert_eq!(result.date, Utc.timestamp_opt(1611427200, 0).unwrap());
+        assert_eq!(result.event.id, 5206);
+        assert_eq!(
+            result.team1,
+            Some(Team::new(6665, "Astralis", "imglink-astralis", None))
+        );
+        assert_eq!(result.team2.unwrap().id, 9565);
+        assert_eq!(
+            result.score,
+            RoundScore {
+                team1: 16,
+                team2: 5
+            }
+        );
+
+        let rounds: Vec<_> = result
+            .rounds
+            .iter()
+            .map(|r| (r.number, &r.winner, &r.side, &r.win_type))
+            .collect();
+        assert_eq!(
+            rounds,
+            vec![
+                (1, &WhichTeam::First, &Side::CT, &RoundWinType::Elimination),
+                (2, &WhichTeam::Second, &Side::T, &RoundWinType::Elimination),
+                (3, &WhichTeam::First, &Side::CT, &RoundWinType::BombDefused),
+                (4, &WhichTeam::First, &Side::CT, &RoundWinType::TimeRanOut),
+                (5, &WhichTeam::First, &Side::T, &RoundWinType::BombExploded),
+            ]
+        );
+        assert_eq!(result.rounds[1].score, RoundScore { team1: 1, team2: 1 });
+
+        assert_eq!(result.stats.len(), 3);
+        assert_eq!(
+            result.stats[0],
+            DetailedPerformance(
+                Player {
+                    id: 7398,
+                    nickname: "dupreeh".to_string()
+                },
+                DetailedStats {
+                    kills: 23,
+                    headshots: 11,
+                    assists: 4,
+                    flash_assists: 1,
+                    deaths: 12,
+                    kast: 81.0,
+                    adr: 104.2,
+                    first_kills_diff: 3,
+                    rating: 1.71,
+                }
+            )
+        );
+        assert_eq!(result.stats[1].1.first_kills_diff, -1);
+        assert_eq!(result.stats[2].0.nickname, "ZywOo");
+    }
+}
@@ -5,11 +5,12 @@ completeness. If you think an edge case is not correctly parsed, feel free to cr
 and attach a sample of html that is not correctly recognized.
 */
 use chrono::DateTime;
-use chrono::NaiveDateTime;
+use chrono::TimeZone;
 use chrono::Utc;
 use tl::NodeHandle;
 
 use crate::data::*;
+use crate::request::HLTV_ROOT;
 use crate::tl_extensions::*;
 use crate::ConvertInstance;
 use crate::{Error, Error::ConversionError};
@@ -17,17 +18,25 @@ use crate::{Error, Error::ConversionError};
 impl ConvertInstance for MatchPage {
     fn convert<'a>(d: &'a tl::VDom<'a>) -> Result<MatchPage, Error> {
         let root = get_root(d)?.to_rich(d);
+        let format = get_matchformat(root);
+        let team1 = get_team(root, "team1-gradient");
         Ok(MatchPage {
             id: get_id(d)?,
             status: get_matchstatus(root)?,
-            team1: get_team(root, "team1-gradient"),
+            head_to_head: get_head_to_head(root, team1.as_ref().map(|t| t.id)),
+            team1,
             team2: get_team(root, "team2-gradient"),
             event: get_event(root)?,
             date: get_date(root)?,
-            format: get_matchformat(root)?,
-            score: get_score(root),
+            score: get_score(root, &format),
+            round_score: get_round_score(root, &format),
+            format,
             maps: get_mapscores(root)?,
             stats: get_performance(root),
+            media: get_media(root),
+            lineups: get_lineups(root),
+            past_matches: get_past_matches(root),
+            odds: get_odds(root),
         })
     }
 }
@@ -105,23 +114,70 @@ pub fn get_date(h: RichNode) -> Result<DateTime<Utc>, Error> {
         .get_attr("data-unix")?
         .ok_or(ConversionError("no data-unix attribute"))?;
 
-    Ok(DateTime::<Utc>::from_utc(
-        NaiveDateTime::from_timestamp(timestamp / 1000, 0),
-        Utc,
-    ))
+    Utc.timestamp_opt(timestamp / 1000, 0)
+        .single()
+        .ok_or(ConversionError(
+            "data-unix attribute is not a valid timestamp",
+        ))
 }
 
-pub fn get_score(h: RichNode) -> Option<MatchScore> {
+/// Returns the match score in maps. The score is derived from the won maps, since the
+/// numbers in the header are rounds for Bo1s and maps for every other format. If no map
+/// has been decided yet, the header is used instead (e.g. for forfeits).
+pub fn get_score(h: RichNode, format: &MatchFormat) -> Option<MatchScore> {
+    let (won1, won2) = get_map_wins(h);
+    if won1 + won2 > 0 {
+        return Some(MatchScore {
+            team1: won1,
+            team2: won2,
+        });
+    }
+    let (team1, team2) = get_header_score(h)?;
+    match format {
+        MatchFormat::Bo1 => {
+            let won = h.find("team1-gradient").child(1)?.has_class("won")?;
+            let lost = h.find("team1-gradient").child(1)?.has_class("lost")?;
+            match (won, lost) {
+                (true, _) => Some(MatchScore { team1: 1, team2: 0 }),
+                (_, true) => Some(MatchScore { team1: 0, team2: 1 }),
+                _ => Some(MatchScore { team1: 0, team2: 0 }),
+            }
+        }
+        MatchFormat::Unknown => None,
+        _ => Some(MatchScore { team1, team2 }),
+    }
+}
+
+/// Returns the round score of a Bo1 match as displayed in the header. This score
+/// is also updated while the map is live. Returns `None` for all other formats.
+pub fn get_round_score(h: RichNode, format: &MatchFormat) -> Option<RoundScore> {
+    if *format != MatchFormat::Bo1 {
+        return None;
+    }
+    let (team1, team2) = get_header_score(h)?;
+    Some(RoundScore { team1, team2 })
+}
+
+/// Returns the two numbers shown next to the team names in the match header.
+fn get_header_score(h: RichNode) -> Option<(u32, u32)> {
     let team1: u32 = h.find("team1-gradient").child(1)?.inner_parse().ok()??;
     let team2: u32 = h.find("team2-gradient").child(1)?.inner_parse().ok()??;
-    // if one of the scores is high enough, it has to be a bo1
-    if team1 > 8 && team1 > team2 {
-        return Some(MatchScore { team1: 1, team2: 0 });
-    }
-    if team2 > 8 && team2 > team1 {
-        return Some(MatchScore { team1: 0, team2: 1 });
+    Some((team1, team2))
+}
+
+/// Counts the maps that have been won by either team. Maps that are live or
+/// haven't been played are not counted.
+fn get_map_wins(h: RichNode) -> (u32, u32) {
+    let mut result = (0, 0);
+    for m in h.find("maps").find_all("mapholder") {
+        if m.find("results-left").has_class("won") == Some(true) {
+            result.0 += 1;
+        }
+        if m.find("results-right").has_class("won") == Some(true) {
+            result.1 += 1;
+        }
     }
-    Some(MatchScore { team1, team2 })
+    result
 }
 
 pub fn get_mapscores(h: RichNode) -> Result<Vec<MapScore>, Error> {
@@ -154,20 +210,61 @@ pub fn get_mapscores(h: RichNode) -> Result<Vec<MapScore>, Error> {
             team2: team2
                 .parse()
                 .map_err(|_| ConversionError("cant convert 2nd team's map score"))?,
+            stats_id: get_stats_id(m),
         })
     }
     Ok(result)
 }
 
-pub fn get_matchformat(h: RichNode) -> Result<MatchFormat, Error> {
+/// Returns the map stats ID linked in the given mapholder.
+fn get_stats_id(h: RichNode) -> Option<u32> {
+    h.find("results-stats")
+        .get_attr_str("href")?
+        .split('/')
+        .nth(4)?
+        .parse()
+        .ok()
+}
+
+/// Returns the format of the match. The format is primarily read from the veto box
+/// (e.g. `Best of 3 (LAN)`), because the number of map containers doesn't always
+/// match the format (forfeits, Bo2s, unusual layouts). Falls back to counting maps,
+/// and returns [`MatchFormat::Unknown`] if neither gives a result.
+pub fn get_matchformat(h: RichNode) -> MatchFormat {
+    let veto_text = h.find("maps").find("veto-box").inner_text();
+    if let Some(format) = veto_text.as_deref().and_then(parse_format_text) {
+        return format;
+    }
     match h.find_all("mapholder").len() {
-        1 => Ok(MatchFormat::Bo1),
-        3 => Ok(MatchFormat::Bo3),
-        5 => Ok(MatchFormat::Bo5),
-        7 => Ok(MatchFormat::Bo7),
-        _ => Err(ConversionError(
-            "can't determine match format. weird number of maps.",
-        )),
+        1 => MatchFormat::Bo1,
+        2 => MatchFormat::Bo2,
+        3 => MatchFormat::Bo3,
+        5 => MatchFormat::Bo5,
+        7 => MatchFormat::Bo7,
+        _ => MatchFormat::Unknown,
+    }
+}
+
+/// Parses a textual format description like `Best of 3 (Online)` or `bo2`.
+fn parse_format_text(text: &str) -> Option<MatchFormat> {
+    let text = text.to_lowercase();
+    let count = ["best of", "bo"].iter().find_map(|prefix| {
+        text.match_indices(prefix).find_map(|(i, _)| {
+            let digits: String = text[i + prefix.len()..]
+                .trim_start()
+                .chars()
+                .take_while(|c| c.is_ascii_digit())
+                .collect();
+            digits.parse::<u32>().ok()
+        })
+    })?;
+    match count {
+        1 => Some(MatchFormat::Bo1),
+        2 => Some(MatchFormat::Bo2),
+        3 => Some(MatchFormat::Bo3),
+        5 => Some(MatchFormat::Bo5),
+        7 => Some(MatchFormat::Bo7),
+        _ => None,
     }
 }
 
@@ -235,6 +332,250 @@ fn get_performance_player(h: RichNode) -> Option<Performance> {
     Some(Performance(p, s))
 }
 
+/// Returns the lineups of both teams. Lineups without any valid player are skipped.
+pub fn get_lineups(h: RichNode) -> Vec<Lineup> {
+    let mut result = Vec::new();
+    let teams = [WhichTeam::First, WhichTeam::Second];
+    for (l, team) in h.find("lineups").find_all("lineup").into_iter().zip(teams) {
+        let players: Vec<LineupPlayer> = l
+            .find_all("player")
+            .into_iter()
+            .filter_map(get_lineup_player)
+            .collect();
+        if !players.is_empty() {
+            result.push(Lineup { team, players });
+        }
+    }
+    result
+}
+
+/// Parses a single player cell of a lineup. Cells containing only the player
+/// image are ignored.
+fn get_lineup_player(h: RichNode) -> Option<LineupPlayer> {
+    let n = h.find("flagAlign");
+    let player = Player {
+        id: n.get_attr("data-player-id").ok()??,
+        nickname: n.find("text-ellipsis").inner_text()?,
+    };
+    Some(LineupPlayer {
+        player,
+        country: n.find("flag").get_attr_str("title"),
+        stand_in: h.find("stand-in").n.is_some(),
+    })
+}
+
+/// Returns the head-to-head summary of both teams. The listing on HLTV doesn't
+/// necessarily use the team order of the match, so rows are reordered based on
+/// the ID of the first team.
+pub fn get_head_to_head(h: RichNode, team1_id: Option<u32>) -> Option<HeadToHead> {
+    let n = h.find("head-to-head");
+    let counts: Vec<u32> = n
+        .find_all("flexbox-column")
+        .into_iter()
+        .filter_map(|c| c.find("bold").inner_parse().ok().flatten())
+        .collect();
+    if counts.len() != 3 {
+        return None;
+    }
+    // The summary can list the teams in the opposite order of the match
+    let left_id: Option<u32> = n
+        .find("h2h-team")
+        .get_attr_str("href")
+        .and_then(|x| x.split('/').nth(2)?.parse().ok());
+    let swapped = left_id.is_some() && team1_id.is_some() && left_id != team1_id;
+    let (team1_wins, team2_wins) = match swapped {
+        true => (counts[2], counts[0]),
+        false => (counts[0], counts[2]),
+    };
+    let meetings = h
+        .find("head-to-head-listing")
+        .find_all("row")
+        .into_iter()
+        .filter_map(|r| get_past_meeting(r, team1_id?))
+        .collect();
+    Some(HeadToHead {
+        team1_wins,
+        overtimes: counts[1],
+        team2_wins,
+        meetings,
+    })
+}
+
+/// Parses a single row of the head-to-head listing.
+fn get_past_meeting(h: RichNode, team1_id: u32) -> Option<PastMeeting> {
+    let link = h.find("date").find_tag("a");
+    let timestamp: i64 = link.find_tag("span").get_attr("data-unix").ok()??;
+    let event = h.find("event").find_tag("a");
+    let (left, right) = parse_dash_score(&h.find("result").inner_text()?)?;
+    let left_id: u32 = h
+        .find("team1")
+        .find_tag("a")
+        .get_attr_str("href")?
+        .split('/')
+        .nth(2)?
+        .parse()
+        .ok()?;
+    let score = match left_id == team1_id {
+        true => RoundScore {
+            team1: left,
+            team2: right,
+        },
+        false => RoundScore {
+            team1: right,
+            team2: left,
+        },
+    };
+    Some(PastMeeting {
+        id: link.get_attr_str("href")?.split('/').nth(2)?.parse().ok()?,
+        date: Utc.timestamp_opt(timestamp / 1000, 0).single()?,
+        event: Event {
+            id: event
+                .get_attr_str("href")?
+                .split('/')
+                .nth(2)?
+                .parse()
+                .ok()?,
+            name: event.inner_text()?,
+        },
+        map: h.find("dynamic-map-name-full").inner_text()?.into(),
+        winner: match score.team1.cmp(&score.team2) {
+            std::cmp::Ordering::Greater => WhichTeam::First,
+            std::cmp::Ordering::Less => WhichTeam::Second,
+            std::cmp::Ordering::Equal => WhichTeam::None,
+        },
+        score,
+    })
+}
+
+/// Returns the recent results of both teams.
+pub fn get_past_matches(h: RichNode) -> Vec<PastMatches> {
+    let mut result = Vec::new();
+    let teams = [WhichTeam::First, WhichTeam::Second];
+    for (b, team) in h
+        .find("past-matches")
+        .find_all("past-matches-box")
+        .into_iter()
+        .zip(teams)
+    {
+        let matches = b
+            .find("past-matches-table")
+            .find_all("table")
+            .into_iter()
+            .filter_map(get_past_match)
+            .collect();
+        result.push(PastMatches { team, matches });
+    }
+    result
+}
+
+/// Parses a single row of a team's past matches.
+fn get_past_match(h: RichNode) -> Option<PastMatch> {
+    let opponent = h.find("opponent").find_tag("a");
+    let res = h.find("result");
+    let link = res.find_tag("a");
+    let (score, opponent_score) = parse_dash_score(&link.inner_text()?)?;
+    Some(PastMatch {
+        id: link.get_attr_str("href")?.split('/').nth(2)?.parse().ok()?,
+        opponent_id: opponent
+            .get_attr_str("href")?
+            .split('/')
+            .nth(2)?
+            .parse()
+            .ok()?,
+        opponent: opponent.inner_text()?,
+        score,
+        opponent_score,
+        won: res.has_class("won")?,
+    })
+}
+
+/// Parses scores of the form `16 - 14`.
+fn parse_dash_score(s: &str) -> Option<(u32, u32)> {
+    let mut it = s.split('-').map(|x| x.trim().parse::<u32>());
+    Some((it.next()?.ok()?, it.next()?.ok()?))
+}
+
+/// Returns the betting odds of all visible providers. Providers without odds
+/// for this match are skipped.
+pub fn get_odds(h: RichNode) -> Vec<Odds> {
+    let mut result = Vec::new();
+    for p in h.find("match-betting-list").find_all("provider") {
+        // Providers hidden for the visitor's region still carry valid odds
+        if p.find("noOdds").n.is_some() {
+            continue;
+        }
+        let cells = p.find_all("odds-cell");
+        if cells.len() != 2 {
+            continue;
+        }
+        let odds = |c: RichNode| c.inner_text()?.trim().parse::<f32>().ok();
+        let provider = p.find_tag("img").get_attr_str("title").or_else(|| {
+            p.get_attr_str("class")?
+                .split_whitespace()
+                .find_map(|c| c.strip_prefix("gprov_"))
+                .map(|c| c.to_string())
+        });
+        if let (Some(provider), Some(team1), Some(team2)) =
+            (provider, odds(cells[0]), odds(cells[1]))
+        {
+            result.push(Odds {
+                provider,
+                team1,
+                team2,
+            });
+        }
+    }
+    result
+}
+
+/// Returns all streams, VODs, demos and highlights listed on the match page.
+pub fn get_media(h: RichNode) -> MatchMedia {
+    let mut result = MatchMedia::default();
+    for b in h.find_all("stream-box") {
+        if let Some(link) = b.get_attr_str_esc("data-demo-link") {
+            result.demos.push(Demo {
+                url: format!("{}{}", HLTV_ROOT, link.trim_start_matches('/')),
+            });
+            continue;
+        }
+        let url = match b.get_attr_str_esc("data-stream-embed") {
+            Some(x) => x,
+            None => continue,
+        };
+        let name = match b.find("stream-box-embed").inner_text() {
+            Some(x) => x.trim().to_string(),
+            None => continue,
+        };
+        // VODs are listed in the same way as streams, but without flag and viewers
+        if b.find("stream-flag").n.is_none() && b.find("viewers").n.is_none() {
+            result.vods.push(Vod {
+                platform: url.clone().into(),
+                title: name,
+                url,
+            });
+            continue;
+        }
+        result.streams.push(Stream {
+            platform: url.clone().into(),
+            name,
+            url,
+            language: b.find("stream-flag").get_attr_str("title"),
+            viewers: b.find("viewers").inner_parse().ok().flatten(),
+        });
+    }
+    for x in h.find_all("highlight") {
+        let url = x.get_attr_str_esc("data-highlight-embed");
+        let title = x.inner_text();
+        if let (Some(url), Some(title)) = (url, title) {
+            result.highlights.push(Highlight {
+                title: title.trim().to_string(),
+                url,
+            });
+        }
+    }
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -252,5 +593,233 @@ mod tests {
         assert_eq!(t2.logo, "imglink-vitality");
         assert_eq!(t1.alt_logo.unwrap(), "imglink-astralis-night");
         assert_eq!(t2.alt_logo, None);
+        assert_eq!(result.format, MatchFormat::Bo3);
+        assert_eq!(result.score, Some(MatchScore { team1: 2, team2: 1 }));
+        assert_eq!(result.round_score, None);
+        let stats_ids: Vec<_> = result.maps.iter().map(|m| m.stats_id).collect();
+        assert_eq!(stats_ids, vec![Some(114119), Some(114120), Some(114121)]);
+    }
+
+    /// Tests if an upcoming match without results is parsed, including lineups.
+    #[test]
+    pub fn upcoming_bo3() {
+        let input = include_str!("../testdata/matchPages/upcoming_bo3.html");
+        let dom = tl::parse(input, tl::ParserOptions::default()).unwrap();
+        let result = MatchPage::convert(&dom).unwrap();
+        assert_eq!(result.status, MatchStatus::Upcoming);
+        assert_eq!(result.format, MatchFormat::Bo3);
+        assert_eq!(result.score, None);
+        assert_eq!(result.maps, Vec::new());
+        assert_eq!(result.stats, Vec::new());
+        assert_eq!(result.lineups.len(), 2);
+        assert_eq!(result.lineups[0].team, WhichTeam::First);
+        assert_eq!(
+            result.lineups[0].players[0],
+            LineupPlayer {
+                player: Player {
+                    id: 8183,
+                    nickname: "huNter-".to_string()
+                },
+                country: Some("Bosnia and Herzegovina".to_string()),
+                stand_in: false,
+            }
+        );
+        assert_eq!(result.lineups[1].team, WhichTeam::Second);
+        assert_eq!(result.lineups[1].players.len(), 2);
+        assert!(!result.lineups[1].players[0].stand_in);
+        assert!(result.lineups[1].players[1].stand_in);
+    }
+
+    /// Tests if the head-to-head box and past matches are parsed.
+    #[test]
+    pub fn head_to_head() {
+        let input = include_str!("../testdata/matchPages/finished_bo3.html");
+        let dom = tl::parse(input, tl::ParserOptions::default()).unwrap();
+        let result = MatchPage::convert(&dom).unwrap();
+        let h2h = result.head_to_head.unwrap();
+        assert_eq!(h2h.team1_wins, 4);
+        assert_eq!(h2h.team2_wins, 2);
+        assert_eq!(h2h.overtimes, 1);
+        assert_eq!(h2h.meetings.len(), 2);
+        // team order is swapped in the listing
+        assert_eq!(
+            h2h.meetings[0],
+            PastMeeting {
+                id: 2345198,
+                date: Utc.timestamp_opt(1607184000, 0).unwrap(),
+                event: Event {
+                    id: 5151,
+                    name: "IEM Global Challenge 2020".to_string()
+                },
+                map: Map::Nuke,
+                winner: WhichTeam::Second,
+                score: RoundScore {
+                    team1: 17,
+                    team2: 19
+                },
+            }
+        );
+        assert_eq!(h2h.meetings[1].winner, WhichTeam::First);
+
+        assert_eq!(result.past_matches.len(), 2);
+        assert_eq!(result.past_matches[0].team, WhichTeam::First);
+        assert_eq!(result.past_matches[0].matches.len(), 2);
+        assert_eq!(
+            result.past_matches[0].matches[1],
+            PastMatch {
+                id: 2346050,
+                opponent_id: 4608,
+                opponent: "Natus Vincere".to_string(),
+                score: 0,
+                opponent_score: 2,
+                won: false,
+            }
+        );
+        assert_eq!(result.past_matches[1].matches.len(), 1);
+        assert!(result.past_matches[1].matches[0].won);
+    }
+
+    /// Tests if the win counts follow the match order when the head-to-head box
+    /// lists the teams the other way around.
+    #[test]
+    pub fn head_to_head_swapped() {
+        let input = include_str!("../testdata/matchPages/finished_bo3.html")
+            .replace(
+                "<a href=\"/team/6665/astralis\" class=\"h2h-team\">",
+                "<a href=\"/team/tmp\" class=\"h2h-team\">",
+            )
+            .replace(
+                "<a href=\"/team/9565/vitality\" class=\"h2h-team\">",
+                "<a href=\"/team/6665/astralis\" class=\"h2h-team\">",
+            )
+            .replace(
+                "<a href=\"/team/tmp\" class=\"h2h-team\">",
+                "<a href=\"/team/9565/vitality\" class=\"h2h-team\">",
+            );
+        let dom = tl::parse(&input, tl::ParserOptions::default()).unwrap();
+        let h2h = MatchPage::convert(&dom).unwrap().head_to_head.unwrap();
+        assert_eq!(h2h.team1_wins, 2);
+        assert_eq!(h2h.team2_wins, 4);
+        assert_eq!(h2h.overtimes, 1);
+    }
+
+    /// Tests if betting odds are parsed, including region-hidden providers, and providers
+    /// without odds are skipped.
+    #[test]
+    pub fn odds() {
+        let input = include_str!("../testdata/matchPages/finished_bo3.html");
+        let dom = tl::parse(input, tl::ParserOptions::default()).unwrap();
+        let result = MatchPage::convert(&dom).unwrap();
+        assert_eq!(
+            result.odds,
+            vec![
+                Odds {
+                    provider: "GG.BET".to_string(),
+                    team1: 1.55,
+                    team2: 2.35,
+                },
+                Odds {
+                    provider: "thunderpick".to_string(),
+                    team1: 1.61,
+                    team2: 2.22,
+                },
+                Odds {
+                    provider: "pinnacle".to_string(),
+                    team1: 1.50,
+                    team2: 2.50,
+                },
+            ]
+        );
+    }
+
+    /// Tests if streams, VODs, demos and highlights are found.
+    #[test]
+    pub fn media() {
+        let input = include_str!("../testdata/matchPages/finished_bo3.html");
+        let dom = tl::parse(input, tl::ParserOptions::default()).unwrap();
+        let media = MatchPage::convert(&dom).unwrap().media;
+        assert_eq!(
+            media.streams,
+            vec![
+                Stream {
+                    platform: StreamPlatform::Twitch,
+                    name: "BLAST Premier".to_string(),
+                    url: "https://player.twitch.tv/?channel=blastpremier&parent=www.hltv.org"
+                        .to_string(),
+                    language: Some("United Kingdom".to_string()),
+                    viewers: Some(45312),
+                },
+                Stream {
+                    platform: StreamPlatform::YouTube,
+                    name: "BLAST Danmark".to_string(),
+                    url: "https://www.youtube.com/embed/blastdk".to_string(),
+                    language: Some("Denmark".to_string()),
+                    viewers: None,
+                },
+                Stream {
+                    // only mentions twitch in the query string
+                    platform: StreamPlatform::Other,
+                    name: "csfans".to_string(),
+                    url: "https://embed.csfans.net/player?mirror=twitch.tv/blastpremier"
+                        .to_string(),
+                    language: Some("Poland".to_string()),
+                    viewers: None,
+                },
+                Stream {
+                    platform: StreamPlatform::Twitch,
+                    name: "Watch party FR".to_string(),
+                    url: "https://player.twitch.tv/?channel=ottolol".to_string(),
+                    language: Some("France".to_string()),
+                    viewers: Some(812),
+                },
+            ]
+        );
+        assert_eq!(media.vods.len(), 3);
+        assert_eq!(media.vods[0].title, "Watch map 1 - Dust2");
+        assert_eq!(media.vods[0].platform, StreamPlatform::Twitch);
+        assert_eq!(
+            media.demos,
+            vec![Demo {
+                url: "https://www.hltv.org/download/demo/62543".to_string()
+            }]
+        );
+        assert_eq!(
+            media.highlights,
+            vec![Highlight {
+                title: "ZywOo 4k vs Astralis".to_string(),
+                url: "https://clips.twitch.tv/embed?clip=ZywOo4k".to_string()
+            }]
+        );
+    }
+
+    /// Tests if a finished bo1 with a short (MR12) score is correctly parsed.
+    #[test]
+    pub fn concluded_bo1() {
+        let input = include_str!("../testdata/matchPages/finished_bo1.html");
+        let dom = tl::parse(input, tl::ParserOptions::default()).unwrap();
+        let result = MatchPage::convert(&dom).unwrap();
+        assert_eq!(result.format, MatchFormat::Bo1);
+        assert_eq!(result.score, Some(MatchScore { team1: 1, team2: 0 }));
+        assert_eq!(
+            result.round_score,
+            Some(RoundScore {
+                team1: 13,
+                team2: 7
+            })
+        );
+        assert_eq!(result.maps, vec![MapScore::new(Map::Mirage, 13, 7)]);
+    }
+
+    /// Tests if format descriptions from the veto box are recognized.
+    #[test]
+    pub fn format_text() {
+        assert_eq!(parse_format_text("Best of 3 (LAN)"), Some(MatchFormat::Bo3));
+        assert_eq!(
+            parse_format_text("Best of 2 (Online)\n\n* Group stage"),
+            Some(MatchFormat::Bo2)
+        );
+        assert_eq!(parse_format_text("bo5"), Some(MatchFormat::Bo5));
+        assert_eq!(parse_format_text("Best of 4 (Online)"), None);
+        assert_eq!(parse_format_text("* Team forfeited"), None);
     }
 }
@@ -10,8 +10,42 @@ If the conversion breaks at any point in the future, feel free to create an issu
 GitHub repository or submit a pull request.
 */
 
+use crate::data::SocialLink;
+use crate::tl_extensions::RichNode;
+
 pub mod player;
 pub mod upcoming;
 pub mod match_page;
 pub mod team_page;
 pub mod results;
+pub mod map_stats;
+pub mod performance;
+pub mod economy;
+pub mod team_stats;
+pub mod player_page;
+pub mod player_stats;
+pub mod transfers;
+pub mod search;
+pub mod leaderboard;
+pub mod ranking;
+pub mod valve_ranking;
+pub mod event;
+pub mod events;
+
+/// Returns all social media links listed in the `socialMediaButtons` box of
+/// a team or player profile.
+pub(crate) fn get_social(h: RichNode) -> Vec<SocialLink> {
+    let n = h.find("socialMediaButtons");
+    let mut result = Vec::new();
+    let mut i = 0;
+    while let Some(link) = n.child(i) {
+        i += 1;
+        if let Some(url) = link.get_attr_str_esc("href") {
+            result.push(SocialLink {
+                platform: url.clone().into(),
+                url,
+            });
+        }
+    }
+    result
+}
@@ -0,0 +1,140 @@
+/*!
+This module defines how a match performance page (kill matrices) is parsed.
+*/
+use std::collections::HashMap;
+
+use crate::data::*;
+use crate::tl_extensions::*;
+use crate::ConvertInstance;
+use crate::{Error, Error::ConversionError};
+
+impl ConvertInstance for PerformancePage {
+    fn convert<'a>(d: &'a tl::VDom<'a>) -> Result<PerformancePage, Error> {
+        Ok(PerformancePage {
+            id: get_id(d)?,
+            all: get_kill_matrix(d, "ALL-content")?,
+            first_kills: get_kill_matrix(d, "FIRST_KILL-content")?,
+            awp_kills: get_kill_matrix(d, "AWP-content")?,
+        })
+    }
+}
+
+/// Returns the map stats ID.
+fn get_id(d: &tl::VDom) -> Result<u32, Error> {
+    for elem in d.query_selector("link[href]").unwrap() {
+        let link = elem.to_rich(d).get_attr_str("href").unwrap();
+        if link.contains("https://www.hltv.org/stats/matches/performance/mapstatsid/") {
+            let chunk = link
+                .split('/')
+                .nth(7)
+                .ok_or(ConversionError("error parsing performance link tag"))?;
+            return chunk.parse().map_err(|_| Error::ParseError);
+        }
+    }
+    Err(ConversionError("couldn't find link tag with map stats ID"))
+}
+
+/// Returns the kill matrix contained in the element with the given ID. The first
+/// row contains the players of team 2, every following row starts with a player
+/// of team 1 and the kills against each player of team 2.
+fn get_kill_matrix(d: &tl::VDom, id: &str) -> Result<KillMatrix, Error> {
+    let table = d
+        .get_element_by_id(id)
+        .ok_or(ConversionError("kill matrix not found"))?
+        .to_rich(d)
+        .find("stats-table");
+    let header = table
+        .child(0)
+        .ok_or(ConversionError("kill matrix has no header row"))?;
+    let opponents: Vec<Option<Player>> = header
+        .find_all("killmatrix-topbar")
+        .into_iter()
+        .skip(1)
+        .map(get_player)
+        .collect();
+
+    let mut result = HashMap::new();
+    let mut i = 1;
+    while let Some(row) = table.child(i) {
+        i += 1;
+        let player = match get_player(row.find("killmatrix-player")) {
+            Some(p) => p,
+            None => continue,
+        };
+        for (cell, opponent) in row.find_all("killmatrix-cell").into_iter().zip(&opponents) {
+            let opponent = match opponent {
+                Some(p) => p.clone(),
+                None => continue,
+            };
+            let duel = Duel {
+                kills: cell
+                    .find("team1-player-score")
+                    .inner_parse()?
+                    .ok_or(ConversionError("kill matrix cell has no kills"))?,
+                deaths: cell
+                    .find("team2-player-score")
+                    .inner_parse()?
+                    .ok_or(ConversionError("kill matrix cell has no deaths"))?,
+            };
+            result.insert((player.clone(), opponent), duel);
+        }
+    }
+    Ok(KillMatrix(result))
+}
+
+/// Returns the player linked in the given cell.
+fn get_player(h: RichNode) -> Option<Player> {
+    let link = h.find_tag("a");
+    Some(Player {
+        id: link
+            .get_attr_str("href")?
+            .split('/')
+            .find_map(|x| x.parse().ok())?,
+        nickname: link.inner_text()?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn player(id: u32, nickname: &str) -> Player {
+        Player {
+            id,
+            nickname: nickname.to_string(),
+        }
+    }
+
+    /// Tests if all three kill matrices are parsed.
+    #[test]
+    pub fn kill_matrix() {
+        let input = include_str!("../testdata/performancePages/inferno.html");
+        let dom = tl::parse(input, tl::ParserOptions::default()).unwrap();
+        let result = PerformancePage::convert(&dom).unwrap();
+        let dupreeh = player(7398, "dupreeh");
+        let gla1ve = player(7412, "gla1ve");
+        let zywoo = player(11893, "ZywOo");
+        let apex = player(7322, "apEX");
+
+        assert_eq!(result.id, 114121);
+        assert_eq!(result.all.0.len(), 4);
+        assert_eq!(
+            result.all.get(&dupreeh, &zywoo),
+            Some(Duel {
+                kills: 4,
+                deaths: 2
+            })
+        );
+        // reversed perspective
+        assert_eq!(
+            result.all.get(&zywoo, &gla1ve),
+            Some(Duel {
+                kills: 5,
+                deaths: 1
+            })
+        );
+        assert_eq!(result.all.get(&dupreeh, &gla1ve), None);
+        assert_eq!(result.first_kills.get(&apex, &dupreeh).unwrap().kills, 1);
+        assert_eq!(result.awp_kills.get(&gla1ve, &zywoo).unwrap().deaths, 3);
+    }
+}
@@ -0,0 +1,210 @@
+/*!
+This module defines how a player profile page is parsed.
+*/
+use chrono::NaiveDate;
+use tl::NodeHandle;
+
+use crate::converter::get_social;
+use crate::data::*;
+use crate::tl_extensions::*;
+use crate::ConvertInstance;
+use crate::{Error, Error::ConversionError};
+
+impl ConvertInstance for PlayerPage {
+    fn convert<'a>(d: &'a tl::VDom<'a>) -> Result<PlayerPage, Error> {
+        let root = get_root(d)?.to_rich(d);
+        let realname = root.find("playerRealname");
+        Ok(PlayerPage {
+            id: get_id(d)?,
+            nickname: root
+                .find("playerNickname")
+                .inner_text()
+                .map(|n| n.trim().to_string())
+                .ok_or(ConversionError("couldn't find player nickname"))?,
+            real_name: realname
+                .inner_text()
+                .map(|n| n.trim().to_string())
+                .filter(|n| !n.is_empty()),
+            age: root
+                .find("playerAge")
+                .find("listRight")
+                .inner_text()
+                .and_then(|a| a.split_whitespace().next()?.parse().ok()),
+            country: realname.find("flag").get_attr_str("title"),
+            team: get_team(root),
+            image: root.find("bodyshot-img").get_attr_str_esc("src"),
+            social: get_social(root),
+            stats: get_stats(root),
+            top20: get_top20(root),
+            team_history: get_team_history(root),
+        })
+    }
+}
+
+/// Returns the relevant root node.
+fn get_root(d: &tl::VDom) -> Result<NodeHandle, Error> {
+    d.query_selector("div.playerProfile")
+        .unwrap()
+        .next()
+        .ok_or(ConversionError("no playerProfile node found"))
+}
+
+/// Returns the player ID from the canonical link of the page.
+fn get_id(d: &tl::VDom) -> Result<u32, Error> {
+    for elem in d.query_selector("link[href]").unwrap() {
+        let link = elem.to_rich(d).get_attr_str("href").unwrap();
+        if link.contains("https://www.hltv.org/player/") {
+            let chunk = link
+                .split('/')
+                .nth(4)
+                .ok_or(ConversionError("error parsing player link tag"))?;
+            return chunk.parse().map_err(|_| Error::ParseError);
+        }
+    }
+    Err(ConversionError("couldn't find link tag with player ID"))
+}
+
+/// Returns the current team of the player.
+fn get_team(h: RichNode) -> Option<TeamRef> {
+    let link = h.find("playerTeam").find_tag("a");
+    Some(TeamRef {
+        id: link.get_attr_str("href")?.split('/').nth(2)?.parse().ok()?,
+        name: link.inner_text()?.trim().to_string(),
+    })
+}
+
+/// Returns the headline statistics. Each statistic consists of a label and a value.
+fn get_stats(h: RichNode) -> PlayerSummaryStats {
+    let mut result = PlayerSummaryStats::default();
+    for stat in h.find_all("player-stat") {
+        let label = stat.find_tag("b").inner_text().unwrap_or_default();
+        let value = stat.find("statsVal").inner_text().unwrap_or_default();
+        let value = value.trim().trim_end_matches('%');
+        let label = label.trim().to_lowercase();
+        if label.starts_with("rating") {
+            result.rating = value.parse().ok();
+        } else if label.starts_with("kills / round") {
+            result.kills_per_round = value.parse().ok();
+        } else if label.starts_with("deaths / round") {
+            result.deaths_per_round = value.parse().ok();
+        } else if label.starts_with("adr") {
+            result.adr = value.parse().ok();
+        } else if label.starts_with("headshots") {
+            result.headshot_percentage = value.parse().ok();
+        } else if label.starts_with("maps played") {
+            result.maps_played = value.parse().ok();
+        }
+    }
+    result
+}
+
+/// Returns the top 20 placements, which are listed as `#1 (2021)`.
+fn get_top20(h: RichNode) -> Vec<Top20Placement> {
+    h.find("playerTop20")
+        .find_all("top20ListRight")
+        .into_iter()
+        .filter_map(|p| {
+            let text = p.inner_text()?;
+            let (rank, year) = text.trim().split_once(' ')?;
+            Some(Top20Placement {
+                rank: rank.trim_start_matches('#').parse().ok()?,
+                year: year.trim_matches(|c| c == '(' || c == ')').parse().ok()?,
+            })
+        })
+        .collect()
+}
+
+/// Returns the team history of the player from the team breakdown table.
+fn get_team_history(h: RichNode) -> Vec<TeamMembership> {
+    h.find("team-breakdown")
+        .find_all("team")
+        .into_iter()
+        .filter_map(|row| {
+            let link = row.find("team-name-cell").find_tag("a");
+            let team = TeamRef {
+                id: link.get_attr_str("href")?.split('/').nth(2)?.parse().ok()?,
+                name: link.find("team-name").inner_text()?.trim().to_string(),
+            };
+            let period = row.find("time-period-cell").inner_text()?;
+            let (joined, left) = period.split_once('-').unwrap_or((&period, ""));
+            Some(TeamMembership {
+                team,
+                joined: parse_month(joined),
+                left: parse_month(left),
+                role: row
+                    .find("role-cell")
+                    .inner_text()
+                    .unwrap_or_default()
+                    .into(),
+            })
+        })
+        .collect()
+}
+
+/// Parses dates like `August 2016` to the first day of the month. Returns
+/// `None` for anything else, e.g. `Present`.
+fn parse_month(s: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(&format!("1 {}", s.trim()), "%d %B %Y").ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    /// Tests if a player profile with complete data is parsed correctly.
+    #[test]
+    pub fn top_player() {
+        let input = include_str!("../testdata/playerPages/s1mple.html");
+        let dom = tl::parse(input, tl::ParserOptions::default()).unwrap();
+        let result = PlayerPage::convert(&dom).unwrap();
+        assert_eq!(result.id, 7998);
+        assert_eq!(result.nickname, "s1mple");
+        assert_eq!(result.real_name, Some("Oleksandr Kostyliev".to_string()));
+        assert_eq!(result.age, Some(24));
+        assert_eq!(result.country, Some("Ukraine".to_string()));
+        assert_eq!(
+            result.team,
+            Some(TeamRef {
+                id: 4608,
+                name: "Natus Vincere".to_string()
+            })
+        );
+        assert_eq!(result.social.len(), 2);
+        assert_eq!(result.social[1].platform, SocialPlatform::Twitch);
+        assert_eq!(
+            result.stats,
+            PlayerSummaryStats {
+                rating: Some(1.27),
+                kills_per_round: Some(0.86),
+                deaths_per_round: Some(0.61),
+                adr: Some(87.4),
+                headshot_percentage: Some(40.5),
+                maps_played: Some(1687),
+            }
+        );
+        assert_eq!(result.top20.len(), 3);
+        assert_eq!(
+            result.top20[2],
+            Top20Placement {
+                year: 2017,
+                rank: 2
+            }
+        );
+        assert_eq!(result.team_history.len(), 3);
+        assert_eq!(
+            result.team_history[1],
+            TeamMembership {
+                team: TeamRef {
+                    id: 5378,
+                    name: "FlipSid3".to_string()
+                },
+                joined: NaiveDate::from_ymd_opt(2016, 1, 1),
+                left: NaiveDate::from_ymd_opt(2016, 8, 1),
+                role: RosterStatus::Starter,
+            }
+        );
+        assert_eq!(result.team_history[0].left, None);
+        assert_eq!(result.team_history[2].role, RosterStatus::Substitute);
+    }
+}
@@ -0,0 +1,230 @@
+/*!
+This module defines how the statistics pages of a player are parsed. The overview,
+career and maps subpages are converted separately and merged by
+[`crate::request::player_stats::PlayerStatsRequest`].
+*/
+use std::collections::HashMap;
+
+use crate::data::*;
+use crate::tl_extensions::*;
+use crate::ConvertInstance;
+use crate::{Error, Error::ConversionError};
+
+/// Converts the overview subpage. The yearly and per-map stats are left empty, as
+/// they are listed on the career and maps subpages.
+impl ConvertInstance for PlayerStats {
+    fn convert<'a>(d: &'a tl::VDom<'a>) -> Result<PlayerStats, Error> {
+        Ok(PlayerStats {
+            id: get_id(d)?,
+            overall: get_overall(get_root(d)?)?,
+            years: Vec::new(),
+            maps: HashMap::new(),
+        })
+    }
+}
+
+/// Converts the career subpage.
+impl ConvertInstance for Vec<PlayerYearStats> {
+    fn convert<'a>(d: &'a tl::VDom<'a>) -> Result<Vec<PlayerYearStats>, Error> {
+        Ok(get_years(get_root(d)?))
+    }
+}
+
+/// Converts the maps subpage.
+impl ConvertInstance for HashMap<Map, PlayerMapStats> {
+    fn convert<'a>(d: &'a tl::VDom<'a>) -> Result<HashMap<Map, PlayerMapStats>, Error> {
+        Ok(get_maps(get_root(d)?))
+    }
+}
+
+/// Returns the relevant root node, which is the same on all subpages.
+fn get_root<'a>(d: &'a tl::VDom<'a>) -> Result<RichNode<'a>, Error> {
+    Ok(d.query_selector("div.stats-section")
+        .unwrap()
+        .next()
+        .ok_or(ConversionError("no stats-section node found"))?
+        .to_rich(d))
+}
+
+/// Returns the player ID from the canonical link of the page.
+fn get_id(d: &tl::VDom) -> Result<u32, Error> {
+    for elem in d.query_selector("link[href]").unwrap() {
+        let link = elem.to_rich(d).get_attr_str("href").unwrap();
+        if link.contains("https://www.hltv.org/stats/players/") {
+            return link
+                .split('/')
+                .find_map(|x| x.parse().ok())
+                .ok_or(ConversionError("error parsing player stats link tag"));
+        }
+    }
+    Err(ConversionError("couldn't find link tag with player ID"))
+}
+
+/// Parses a statistic that has to be present on every stats page.
+fn parse<T: std::str::FromStr>(value: &str) -> Result<T, Error> {
+    value
+        .parse()
+        .map_err(|_| ConversionError("can't parse player statistic"))
+}
+
+/// Parses the label/value rows of the statistics box. Fails if one of the
+/// statistics can't be parsed or none of the rows are recognized.
+fn get_overall(h: RichNode) -> Result<PlayerOverallStats, Error> {
+    let mut result = PlayerOverallStats::default();
+    let mut found = false;
+    for row in h.find("statistics").find_all("stats-row") {
+        let label = row
+            .child(0)
+            .and_then(|c| c.inner_text())
+            .unwrap_or_default();
+        let value = row
+            .child(1)
+            .and_then(|c| c.inner_text())
+            .unwrap_or_default();
+        let value = value.trim().trim_end_matches('%');
+        match label.trim().to_lowercase().as_ref() {
+            "total kills" => result.kills = parse(value)?,
+            "total deaths" => result.deaths = parse(value)?,
+            "headshot %" => result.headshot_percentage = parse(value)?,
+            "k/d ratio" => result.kd_ratio = parse(value)?,
+            "damage / round" => result.adr = value.parse().ok(),
+            "maps played" => result.maps_played = parse(value)?,
+            "rounds played" => result.rounds_played = parse(value)?,
+            "kills / round" => result.kills_per_round = parse(value)?,
+            "assists / round" => result.assists_per_round = parse(value)?,
+            "deaths / round" => result.deaths_per_round = parse(value)?,
+            x if x.starts_with("rating") => result.rating = value.parse().ok(),
+            _ => continue,
+        }
+        found = true;
+    }
+    match found {
+        true => Ok(result),
+        false => Err(ConversionError("no statistics rows found")),
+    }
+}
+
+/// Returns the text of the i-th cell of a table row.
+fn cell(row: RichNode, i: u32) -> Option<String> {
+    Some(row.child(i)?.inner_text()?.trim().to_string())
+}
+
+/// Returns the yearly ratings from the career table.
+fn get_years(h: RichNode) -> Vec<PlayerYearStats> {
+    let body = h.find("player-career").find_tag("tbody");
+    let mut result = Vec::new();
+    let mut i = 0;
+    while let Some(row) = body.child(i) {
+        i += 1;
+        let year = cell(row, 0).and_then(|x| x.parse().ok());
+        let maps_played = cell(row, 2).and_then(|x| x.parse().ok());
+        if let (Some(year), Some(maps_played)) = (year, maps_played) {
+            result.push(PlayerYearStats {
+                year,
+                rating: cell(row, 1).and_then(|x| x.parse().ok()),
+                maps_played,
+            });
+        }
+    }
+    result
+}
+
+/// Returns the statistics of every map in the map table.
+fn get_maps(h: RichNode) -> HashMap<Map, PlayerMapStats> {
+    let body = h.find("player-maps").find_tag("tbody");
+    let mut result = HashMap::new();
+    let mut i = 0;
+    while let Some(row) = body.child(i) {
+        i += 1;
+        let stats = || {
+            Some(PlayerMapStats {
+                maps_played: cell(row, 1)?.parse().ok()?,
+                kd_diff: cell(row, 2)?.trim_start_matches('+').parse().ok()?,
+                rating: cell(row, 3)?.parse().ok()?,
+            })
+        };
+        if let (Some(map), Some(stats)) = (cell(row, 0), stats()) {
+            result.insert(map.into(), stats);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    /// Tests if the career totals of a player are parsed from the overview subpage.
+    #[test]
+    pub fn overview() {
+        let input = include_str!("../testdata/playerStatsPages/s1mple.html");
+        let dom = tl::parse(input, tl::ParserOptions::default()).unwrap();
+        let result = PlayerStats::convert(&dom).unwrap();
+        assert_eq!(result.id, 7998);
+        assert_eq!(
+            result.overall,
+            PlayerOverallStats {
+                kills: 37894,
+                deaths: 28126,
+                headshot_percentage: 41.9,
+                kd_ratio: 1.35,
+                adr: Some(86.1),
+                maps_played: 1687,
+                rounds_played: 44104,
+                kills_per_round: 0.86,
+                assists_per_round: 0.10,
+                deaths_per_round: 0.64,
+                rating: Some(1.24),
+            }
+        );
+        assert!(result.years.is_empty());
+        assert!(result.maps.is_empty());
+    }
+
+    /// Tests if the yearly stats of a player are parsed from the career subpage.
+    #[test]
+    pub fn career() {
+        let input = include_str!("../testdata/playerStatsPages/s1mple_career.html");
+        let dom = tl::parse(input, tl::ParserOptions::default()).unwrap();
+        let result = Vec::<PlayerYearStats>::convert(&dom).unwrap();
+        assert_eq!(result.len(), 3);
+        assert_eq!(
+            result[0],
+            PlayerYearStats {
+                year: 2021,
+                rating: Some(1.32),
+                maps_played: 211
+            }
+        );
+        assert_eq!(result[2].rating, None);
+    }
+
+    /// Tests if the per-map stats of a player are parsed from the maps subpage.
+    #[test]
+    pub fn maps() {
+        let input = include_str!("../testdata/playerStatsPages/s1mple_maps.html");
+        let dom = tl::parse(input, tl::ParserOptions::default()).unwrap();
+        let result = HashMap::<Map, PlayerMapStats>::convert(&dom).unwrap();
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[&Map::Inferno].kd_diff, 1701);
+        assert_eq!(
+            result[&Map::Vertigo],
+            PlayerMapStats {
+                maps_played: 88,
+                kd_diff: -12,
+                rating: 1.01
+            }
+        );
+        assert_eq!(result[&Map::Other("Thera".to_string())].maps_played, 4);
+    }
+
+    /// Tests that a malformed statistic fails the conversion instead of defaulting to 0.
+    #[test]
+    pub fn malformed_overall() {
+        let input =
+            include_str!("../testdata/playerStatsPages/s1mple.html").replace("37894", "n/a");
+        let dom = tl::parse(&input, tl::ParserOptions::default()).unwrap();
+        assert!(PlayerStats::convert(&dom).is_err());
+    }
+}
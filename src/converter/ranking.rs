@@ -0,0 +1,121 @@
+/*!
+This module defines how the weekly HLTV team ranking is parsed.
+*/
+use crate::data::*;
+use crate::tl_extensions::*;
+use crate::ConvertInstance;
+use crate::{Error, Error::ConversionError};
+
+impl ConvertInstance for Vec<RankingEntry> {
+    fn convert<'a>(d: &'a tl::VDom<'a>) -> Result<Vec<RankingEntry>, Error> {
+        let root = d
+            .query_selector("div.ranking")
+            .unwrap()
+            .next()
+            .ok_or(ConversionError("no div.ranking container found"))?;
+        let mut result = Vec::new();
+        for h in root.to_rich(d).find_all("ranked-team") {
+            result.push(get_entry(h)?);
+        }
+        Ok(result)
+    }
+}
+
+/// Parses a single team of the ranking. Also used for the Valve standings, which
+/// share the layout.
+pub(crate) fn get_entry(h: RichNode) -> Result<RankingEntry, Error> {
+    let header = h.find("ranking-header");
+    Ok(RankingEntry {
+        rank: header
+            .find("position")
+            .inner_text()
+            .and_then(|x| x.trim().trim_start_matches('#').parse().ok())
+            .ok_or(ConversionError("couldn't parse ranking position"))?,
+        team: TeamRef {
+            id: h
+                .find("moreLink")
+                .get_attr_str("href")
+                .and_then(|x| x.split('/').nth(2)?.parse().ok())
+                .ok_or(ConversionError("couldn't parse ranked team ID"))?,
+            name: header
+                .find("name")
+                .inner_text()
+                .ok_or(ConversionError("no ranked team name found"))?,
+        },
+        logo: header
+            .find("team-logo")
+            .find_tag("img")
+            .get_attr_str_esc("src"),
+        // Points are listed as `(1000 points)`
+        points: header
+            .find("points")
+            .inner_text()
+            .and_then(|x| x.trim_start_matches('(').split(' ').next()?.parse().ok())
+            .ok_or(ConversionError("couldn't parse ranking points"))?,
+        change: get_change(header),
+        players: get_players(h),
+    })
+}
+
+/// Returns the change in position. HLTV shows `-` for unchanged and `New` for new teams.
+fn get_change(h: RichNode) -> Option<i32> {
+    match h.find("change").inner_text()?.trim() {
+        "-" => Some(0),
+        x => x.trim_start_matches('+').parse().ok(),
+    }
+}
+
+fn get_players(h: RichNode) -> Vec<Player> {
+    h.find("lineup-con")
+        .find_all("player-holder")
+        .into_iter()
+        .filter_map(|p| {
+            let link = p.find_tag("a");
+            Some(Player {
+                id: link.get_attr_str("href")?.split('/').nth(2)?.parse().ok()?,
+                nickname: link
+                    .find("rankingNicknames")
+                    .inner_text()?
+                    .trim()
+                    .to_string(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    /// Tests if ranked teams with their points, change and lineups are parsed.
+    #[test]
+    pub fn world() {
+        let input = include_str!("../testdata/rankingPages/world.html");
+        let dom = tl::parse(input, tl::ParserOptions::default()).unwrap();
+        let result = Vec::<RankingEntry>::convert(&dom).unwrap();
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[0].rank, 1);
+        assert_eq!(
+            result[0].team,
+            TeamRef {
+                id: 6667,
+                name: "FaZe".to_string()
+            }
+        );
+        assert_eq!(result[0].points, 1000);
+        assert_eq!(result[0].change, Some(1));
+        assert_eq!(result[0].players.len(), 5);
+        assert_eq!(
+            result[0].players[1],
+            Player {
+                id: 3741,
+                nickname: "NiKo".to_string()
+            }
+        );
+        assert_eq!(result[1].change, Some(-1));
+        assert_eq!(result[2].rank, 30);
+        assert_eq!(result[2].change, None);
+        assert!(result[2].players.is_empty());
+    }
+}
@@ -33,6 +33,7 @@ fn parse_format(h: RichNode) -> Result<MatchFormat, Error> {
         .ok_or(ConversionError("match format can't be found"))?
         .as_str()
     {
+        "bo2" => Ok(MatchFormat::Bo2),
         "bo3" => Ok(MatchFormat::Bo3),
         "bo5" => Ok(MatchFormat::Bo5),
         "bo7" => Ok(MatchFormat::Bo7),
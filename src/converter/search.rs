@@ -0,0 +1,93 @@
+/*!
+This module defines how the search page is parsed.
+*/
+use crate::data::*;
+use crate::tl_extensions::*;
+use crate::ConvertInstance;
+use crate::{Error, Error::ConversionError};
+
+impl ConvertInstance for SearchResults {
+    fn convert<'a>(d: &'a tl::VDom<'a>) -> Result<SearchResults, Error> {
+        let root = d
+            .query_selector("div.search")
+            .unwrap()
+            .next()
+            .ok_or(ConversionError("no div.search container found"))?;
+        let mut result = SearchResults::default();
+        // Hits are grouped into tables by type, but the link target already tells us
+        // what kind of hit it is. Other hits (e.g. news articles) are ignored.
+        for table in root.to_rich(d).find_all("table") {
+            let body = table.find_tag("tbody");
+            let mut i = 0;
+            while let Some(row) = body.child(i) {
+                i += 1;
+                let link = row.find_tag("a");
+                let href = match link.get_attr_str("href") {
+                    Some(x) => x,
+                    None => continue,
+                };
+                let mut chunks = href.split('/').skip(1);
+                let (kind, id) = match (chunks.next(), chunks.next().map(str::parse::<u32>)) {
+                    (Some(kind), Some(Ok(id))) => (kind, id),
+                    _ => continue,
+                };
+                let name = link.inner_text().unwrap_or_default().trim().to_string();
+                match kind {
+                    "team" => result.teams.push(TeamRef { id, name }),
+                    "player" => result.players.push(Player {
+                        id,
+                        nickname: get_nickname(&name),
+                    }),
+                    "events" => result.events.push(Event { id, name }),
+                    _ => (),
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// Player hits are listed with their full name, e.g. `Oleksandr 's1mple' Kostyliev`.
+/// Returns the nickname between the quotes, or the whole name if there are none.
+fn get_nickname(name: &str) -> String {
+    match name.split('\'').nth(1) {
+        Some(nick) if !nick.is_empty() => nick.to_string(),
+        _ => name.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    /// Tests if search hits of each type are parsed.
+    #[test]
+    pub fn search() {
+        let input = include_str!("../testdata/searchPages/navi.html");
+        let dom = tl::parse(input, tl::ParserOptions::default()).unwrap();
+        let result = SearchResults::convert(&dom).unwrap();
+        assert_eq!(
+            result.teams,
+            vec![
+                TeamRef {
+                    id: 4608,
+                    name: "Natus Vincere".to_string()
+                },
+                TeamRef {
+                    id: 9085,
+                    name: "NAVI Junior".to_string()
+                },
+            ]
+        );
+        assert_eq!(
+            result.players,
+            vec![Player {
+                id: 7998,
+                nickname: "s1mple".to_string()
+            }]
+        );
+        assert_eq!(result.events.len(), 1);
+        assert_eq!(result.events[0].id, 6138);
+    }
+}
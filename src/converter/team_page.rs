@@ -1,8 +1,13 @@
 /*!
 This module defines how a team page is parsed.
 */
+use chrono::DateTime;
+use chrono::NaiveDate;
+use chrono::TimeZone;
+use chrono::Utc;
 use tl::NodeHandle;
 
+use crate::converter::get_social;
 use crate::data::*;
 use crate::tl_extensions::*;
 use crate::ConvertInstance;
@@ -11,11 +16,23 @@ use crate::{Error, Error::ConversionError};
 impl ConvertInstance for TeamPage {
     fn convert<'a>(d: &'a tl::VDom<'a>) -> Result<TeamPage, Error> {
         let root = get_root(d)?.to_rich(d);
+        let ranking_history = get_ranking_history(root);
+        let (upcoming, results) = get_matches(d);
         Ok(TeamPage {
-            id: 5,
-            name: "navi".to_string(),
+            id: get_id(d)?,
+            name: get_name(root)?,
             ranking: get_team_rank(root)?,
-            players: get_players(root)?,
+            peak_ranking: ranking_history.iter().map(|p| p.rank).min(),
+            weeks_in_top30: get_stat(root, "weeks in top30").and_then(|s| s.parse().ok()),
+            ranking_history,
+            country: root.find("team-country").find("flag").get_attr_str("title"),
+            social: get_social(root),
+            players: get_players(root),
+            roster: get_roster(root.find("players-table")),
+            upcoming,
+            results,
+            achievements: get_achievements(d),
+            coach: get_coach(root.find("coach-table")),
             logo: get_logo(root)?,
         })
     }
@@ -29,6 +46,29 @@ fn get_root(d: &tl::VDom) -> Result<NodeHandle, Error> {
         .ok_or(ConversionError("no teamProfile node found"))
 }
 
+/// Returns the team ID from the canonical link of the page.
+fn get_id(d: &tl::VDom) -> Result<u32, Error> {
+    for elem in d.query_selector("link[href]").unwrap() {
+        let link = elem.to_rich(d).get_attr_str("href").unwrap();
+        if link.contains("https://www.hltv.org/team/") {
+            let chunk = link
+                .split('/')
+                .nth(4)
+                .ok_or(ConversionError("error parsing team link tag"))?;
+            return chunk.parse().map_err(|_| Error::ParseError);
+        }
+    }
+    Err(ConversionError("couldn't find link tag with team ID"))
+}
+
+/// Returns the name of the team.
+fn get_name(h: RichNode) -> Result<String, Error> {
+    h.find("profile-team-name")
+        .inner_text()
+        .map(|n| n.trim().to_string())
+        .ok_or(ConversionError("couldn't find team name"))
+}
+
 /// Returns the team's logo url.
 fn get_logo(h: RichNode) -> Result<String, Error> {
     h.find("profile-team-logo-container")
@@ -37,44 +77,314 @@ fn get_logo(h: RichNode) -> Result<String, Error> {
         .ok_or(ConversionError("couldn't find logo container or logo"))
 }
 
-/// Returns the hltv team ranking
-fn get_team_rank(h: RichNode) -> Result<u32, Error> {
+/// Returns the hltv team ranking, or `None` if the team is unranked.
+fn get_team_rank(h: RichNode) -> Result<Option<u32>, Error> {
     let rank_txt = h
         .find("profile-team-stats-container")
         .find("right")
         .find_tag("a")
-        .inner_text()
-        .ok_or(ConversionError("couldn't find hltv ranking text container"))?;
-
-    match rank_txt[1..rank_txt.len()].parse::<u32>() {
-        Ok(number) => Ok(number),
+        .inner_text();
+    let rank_txt = match rank_txt {
+        Some(x) => x,
+        None => return Ok(None),
+    };
+    match rank_txt.trim().trim_start_matches('#').parse::<u32>() {
+        Ok(number) => Ok(Some(number)),
         Err(_) => Err(ConversionError("couldn't parse hltv ranking")),
     }
 }
 
+/// Returns the value of the profile stat whose label starts with the given
+/// (lowercase) text.
+fn get_stat(h: RichNode, label: &str) -> Option<String> {
+    h.find("profile-team-stats-container")
+        .find_all("profile-team-stat")
+        .into_iter()
+        .find(|s| {
+            s.find_tag("b")
+                .inner_text()
+                .is_some_and(|t| t.trim().to_lowercase().starts_with(label))
+        })
+        .and_then(|s| s.find("right").inner_text())
+        .map(|s| s.trim().to_string())
+}
+
+/// Returns the ranking development, which is embedded as chart configuration (JSON)
+/// in the page. Data points without a valid date or rank are skipped.
+fn get_ranking_history(h: RichNode) -> Vec<RankingPoint> {
+    let config = match h
+        .find("ranking-development")
+        .find("graph")
+        .get_attr_str_esc("data-fusionchart-config")
+    {
+        Some(x) => x,
+        None => return Vec::new(),
+    };
+    let data = match config.split("\"data\":[").nth(1) {
+        Some(x) => x.split(']').next().unwrap_or_default(),
+        None => return Vec::new(),
+    };
+    data.split('}')
+        .filter_map(|point| {
+            let date = json_field(point, "label")?;
+            Some(RankingPoint {
+                date: NaiveDate::parse_from_str(&date, "%Y-%m-%d").ok()?,
+                rank: json_field(point, "value")?.parse().ok()?,
+            })
+        })
+        .collect()
+}
+
+/// Extracts the value of a flat field from a JSON object, e.g. `"value":"3"` or
+/// `"value":3`. Quotes around the value are removed.
+fn json_field(object: &str, field: &str) -> Option<String> {
+    let start = object.find(&format!("\"{}\":", field))? + field.len() + 3;
+    let value = object[start..].trim_start();
+    let value = match value.strip_prefix('"') {
+        Some(v) => v.split('"').next()?,
+        None => value.split([',', '}']).next()?.trim(),
+    };
+    Some(value.to_string())
+}
+
+/// Returns the upcoming matches and recent results of the team's matches tab.
+/// The tab consists of a headline for each section, followed by one table per event.
+/// Rows that can't be parsed (e.g. live matches or forfeits) are skipped.
+fn get_matches(d: &tl::VDom) -> (Vec<UpcomingMatch>, Vec<MatchResult>) {
+    let mut upcoming = Vec::new();
+    let mut results = Vec::new();
+    let root = match d.get_element_by_id("matchesBox") {
+        Some(x) => x.to_rich(d),
+        None => return (upcoming, results),
+    };
+    let mut is_upcoming = true;
+    let mut i = 0;
+    while let Some(n) = root.child(i) {
+        i += 1;
+        if n.has_class("standard-headline") == Some(true) {
+            let headline = n.inner_text().unwrap_or_default().to_lowercase();
+            is_upcoming = headline.contains("upcoming");
+            continue;
+        }
+        if n.has_class("match-table") != Some(true) {
+            continue;
+        }
+        let event = match n.find("event-header-cell").find_tag("a").inner_text() {
+            Some(x) => x,
+            None => continue,
+        };
+        let rows = n.find_all("team-row").into_iter();
+        if is_upcoming {
+            upcoming.extend(rows.filter_map(|r| get_upcoming_match(r, &event).ok()));
+        } else {
+            results.extend(rows.filter_map(|r| get_match_result(r, &event).ok()));
+        }
+    }
+    (upcoming, results)
+}
+
+/// Parses an upcoming match of the team's matches tab.
+fn get_upcoming_match(h: RichNode, event: &str) -> Result<UpcomingMatch, Error> {
+    let teams = h.find_all("team-flex");
+    let timestamp: i64 = h
+        .find("date-cell")
+        .find_tag("span")
+        .get_attr("data-unix")?
+        .ok_or(ConversionError("no data-unix attribute in match row"))?;
+    Ok(UpcomingMatch {
+        id: get_match_id(h)?,
+        team1: teams.first().and_then(|t| get_match_team(*t)),
+        team2: teams.get(1).and_then(|t| get_match_team(*t)),
+        event: event.to_string(),
+        format: MatchFormat::Unknown,
+        date: Utc
+            .timestamp_opt(timestamp / 1000, 0)
+            .single()
+            .ok_or(ConversionError(
+                "data-unix attribute is not a valid timestamp",
+            ))?,
+        stars: 0,
+    })
+}
+
+/// Parses a result of the team's matches tab.
+fn get_match_result(h: RichNode, event: &str) -> Result<MatchResult, Error> {
+    let scores: Vec<u32> = h
+        .find("score-cell")
+        .find_all("score")
+        .into_iter()
+        .filter_map(|s| s.inner_text()?.trim().parse().ok())
+        .collect();
+    if scores.len() != 2 {
+        return Err(ConversionError("match result has no valid score"));
+    }
+    let name = |class| {
+        h.find(class)
+            .inner_text()
+            .ok_or(ConversionError("no team name found in match row"))
+    };
+    Ok(MatchResult {
+        id: get_match_id(h)?,
+        winner: match scores[0].cmp(&scores[1]) {
+            std::cmp::Ordering::Greater => WhichTeam::First,
+            std::cmp::Ordering::Less => WhichTeam::Second,
+            std::cmp::Ordering::Equal => WhichTeam::None,
+        },
+        team1: name("team-1")?,
+        team2: name("team-2")?,
+        score: Score {
+            score_won: scores[0].max(scores[1]),
+            score_lost: scores[0].min(scores[1]),
+        },
+        event: event.to_string(),
+        format: MatchFormat::Unknown,
+    })
+}
+
+/// Returns the match ID of a row in the team's matches tab.
+fn get_match_id(h: RichNode) -> Result<u32, Error> {
+    h.find("matchpage-button")
+        .get_attr_str("href")
+        .ok_or(ConversionError("no match link found"))?
+        .split('/')
+        .nth(2)
+        .and_then(|id| id.parse().ok())
+        .ok_or(ConversionError("match ID isn't a valid number"))
+}
+
+/// Returns a team of a row in the team's matches tab.
+fn get_match_team(h: RichNode) -> Option<Team> {
+    let link = h.find("team-name");
+    Some(Team {
+        id: link.get_attr_str("href")?.split('/').nth(2)?.parse().ok()?,
+        name: link.inner_text()?,
+        logo: h.find("team-logo").get_attr_str_esc("src")?,
+        alt_logo: None,
+    })
+}
+
+/// Returns the placements of the achievements tab. Dates are looked up in the events
+/// tab, which lists the team's events with their start date.
+fn get_achievements(d: &tl::VDom) -> Vec<Achievement> {
+    let root = match d.get_element_by_id("achievementsBox") {
+        Some(x) => x.to_rich(d),
+        None => return Vec::new(),
+    };
+    let dates = get_event_dates(d);
+    root.find_all("team")
+        .into_iter()
+        .filter_map(|row| {
+            let link = row.find("tournament-name-cell").find_tag("a");
+            let event = Event {
+                id: link.get_attr_str("href")?.split('/').nth(2)?.parse().ok()?,
+                name: link.inner_text()?,
+            };
+            let prize = row
+                .find("prize-cell")
+                .inner_text()
+                .and_then(|p| p.trim().strip_prefix('$')?.replace(',', "").parse().ok());
+            Some(Achievement {
+                date: dates
+                    .iter()
+                    .find(|(id, _)| *id == event.id)
+                    .map(|(_, d)| *d),
+                placement: row.find("achievement").inner_text()?.parse().ok()?,
+                event,
+                prize,
+            })
+        })
+        .collect()
+}
+
+/// Returns the IDs and start dates of all events in the team's events tab.
+fn get_event_dates(d: &tl::VDom) -> Vec<(u32, DateTime<Utc>)> {
+    let root = match d.get_element_by_id("eventsBox") {
+        Some(x) => x.to_rich(d),
+        None => return Vec::new(),
+    };
+    root.find_all("team-event")
+        .into_iter()
+        .filter_map(|e| {
+            let id = e.get_attr_str("href")?.split('/').nth(2)?.parse().ok()?;
+            let timestamp: i64 = e
+                .find("eventbox-date")
+                .find_tag("span")
+                .get_attr("data-unix")
+                .ok()??;
+            Some((id, Utc.timestamp_opt(timestamp / 1000, 0).single()?))
+        })
+        .collect()
+}
+
 /// Returns a collection of players in this team. Does not collect players who
 /// have invalid hltv profile link, id or name.
-fn get_players(h: RichNode) -> Result<Vec<Player>, Error> {
+fn get_players(h: RichNode) -> Vec<Player> {
     let mut result = Vec::new();
     let n = h.find("bodyshot-team");
-    for i in 0..5 {
-        let child = n
-            .child(i)
-            .ok_or(ConversionError("not enough player html tags in DOM"))?;
-        let nickname = child
-            .get_attr_str("title")
-            .ok_or(ConversionError("player tag has no title attr"))?;
-        let id: u32 = child
+    let mut i = 0;
+    while let Some(child) = n.child(i) {
+        i += 1;
+        let nickname = match child.get_attr_str("title") {
+            Some(x) => x,
+            None => continue,
+        };
+        let id = child
             .get_attr_str("href")
-            .ok_or(ConversionError("player tag has no href attr"))?
-            .split('/')
-            .nth(2)
-            .ok_or(ConversionError("href of player link has incorrect format"))?
-            .parse()
-            .map_err(|_| ConversionError("href player id is not a number"))?;
-        result.push(Player { id, nickname });
+            .and_then(|l| l.split('/').nth(2).and_then(|id| id.parse().ok()));
+        if let Some(id) = id {
+            result.push(Player { id, nickname });
+        }
     }
-    Ok(result)
+    result
+}
+
+/// Returns all members listed in the given roster table (either `players-table`
+/// or `coach-table`). Rows with an invalid profile link are skipped, rows without
+/// a status cell are kept with [`RosterStatus::Unknown`].
+fn get_roster(table: RichNode) -> Vec<RosterMember> {
+    let body = table.find_tag("tbody");
+    let mut result = Vec::new();
+    let mut i = 0;
+    while let Some(row) = body.child(i) {
+        i += 1;
+        if let Some(member) = get_roster_member(row) {
+            result.push(member);
+        }
+    }
+    result
+}
+
+/// Returns the coach from the coach table, which has the same layout as the roster.
+fn get_coach(table: RichNode) -> Option<Coach> {
+    let member = get_roster(table).into_iter().next()?;
+    Some(Coach {
+        id: member.player.id,
+        nickname: member.player.nickname,
+        country: member.country,
+        time_on_team: member.time_on_team,
+        maps_coached: member.maps_played,
+    })
+}
+
+/// Parses a single row of a roster table.
+fn get_roster_member(h: RichNode) -> Option<RosterMember> {
+    let link = h.find("playersBox-first-cell").find_tag("a");
+    let nick = link.find("playersBox-playernick");
+    let cells = h.find_all("center-cell");
+    Some(RosterMember {
+        player: Player {
+            id: link.get_attr_str("href")?.split('/').nth(2)?.parse().ok()?,
+            nickname: nick.find("text-ellipsis").inner_text()?,
+        },
+        country: nick.find("flag").get_attr_str("title"),
+        status: h
+            .find("player-status")
+            .inner_text()
+            .unwrap_or_default()
+            .into(),
+        time_on_team: cells.first()?.inner_text()?.trim().to_string(),
+        maps_played: cells.get(1)?.inner_text()?.trim().parse().ok()?,
+    })
 }
 
 #[cfg(test)]
@@ -87,6 +397,110 @@ mod tests {
         let input = include_str!("../testdata/teamPages/navi.html");
         let dom = tl::parse(input, tl::ParserOptions::default()).unwrap();
         let result = TeamPage::convert(&dom).unwrap();
-        println!("{:?}", result);
+        assert_eq!(result.id, 4608);
+        assert_eq!(result.name, "Natus Vincere");
+        assert_eq!(result.ranking, Some(1));
+        assert_eq!(result.peak_ranking, Some(1));
+        assert_eq!(result.weeks_in_top30, Some(92));
+        assert_eq!(result.ranking_history.len(), 3);
+        assert_eq!(
+            result.ranking_history[0],
+            RankingPoint {
+                date: NaiveDate::from_ymd_opt(2021, 12, 20).unwrap(),
+                rank: 3
+            }
+        );
+        assert_eq!(result.country, Some("Ukraine".to_string()));
+        let platforms: Vec<_> = result.social.iter().map(|s| &s.platform).collect();
+        assert_eq!(
+            platforms,
+            vec![
+                &SocialPlatform::Twitter,
+                &SocialPlatform::Facebook,
+                &SocialPlatform::Instagram,
+                &SocialPlatform::Website,
+                &SocialPlatform::Twitter,
+                &SocialPlatform::Website
+            ]
+        );
+        assert_eq!(result.players.len(), 6);
+        assert_eq!(result.roster.len(), 6);
+        assert_eq!(result.achievements.len(), 3);
+        assert_eq!(
+            result.achievements[1],
+            Achievement {
+                event: Event {
+                    id: 6372,
+                    name: "PGL Major Antwerp 2022".to_string()
+                },
+                placement: Placement { best: 2, worst: 2 },
+                date: Some(Utc.timestamp_opt(1652054400, 0).unwrap()),
+                prize: Some(170000),
+            }
+        );
+        assert_eq!(
+            result.achievements[2].placement,
+            Placement { best: 3, worst: 4 }
+        );
+        assert_eq!(result.achievements[2].date, None);
+        assert_eq!(result.achievements[2].prize, None);
+        assert_eq!(result.upcoming.len(), 1);
+        assert_eq!(result.upcoming[0].id, 2356100);
+        assert_eq!(result.upcoming[0].event, "BLAST Premier Spring Final 2022");
+        assert_eq!(
+            *result.upcoming[0].team2.as_ref().unwrap(),
+            Team::new(7020, "Spirit", "imglink-spirit", None)
+        );
+        assert_eq!(result.results.len(), 3);
+        assert_eq!(
+            result.results[0],
+            MatchResult {
+                id: 2356005,
+                winner: WhichTeam::First,
+                team1: "FaZe".to_string(),
+                team2: "Natus Vincere".to_string(),
+                score: Score {
+                    score_won: 2,
+                    score_lost: 0
+                },
+                event: "PGL Major Antwerp 2022".to_string(),
+                format: MatchFormat::Unknown,
+            }
+        );
+        assert_eq!(result.results[2].winner, WhichTeam::None);
+        assert_eq!(result.results[2].event, "BLAST Premier Spring Groups 2022");
+        assert_eq!(
+            result.roster[0],
+            RosterMember {
+                player: Player {
+                    id: 7998,
+                    nickname: "s1mple".to_string()
+                },
+                country: Some("Ukraine".to_string()),
+                status: RosterStatus::Starter,
+                time_on_team: "5 years 6 months".to_string(),
+                maps_played: 1337,
+            }
+        );
+        assert_eq!(result.roster[5].status, RosterStatus::Benched);
+        let coach = result.coach.unwrap();
+        assert_eq!(coach.id, 2042);
+        assert_eq!(coach.nickname, "B1ad3");
+        assert_eq!(coach.maps_coached, 512);
+    }
+
+    /// Tests if the coach is found even if the coach table has no status cell.
+    #[test]
+    pub fn coach_without_status() {
+        let input = include_str!("../testdata/teamPages/navi.html").replace(
+            r#"<td class="players-cell status-cell"><div class="player-status coach">COACH</div></td>"#,
+            "",
+        );
+        let dom = tl::parse(&input, tl::ParserOptions::default()).unwrap();
+        let result = TeamPage::convert(&dom).unwrap();
+        let coach = result.coach.unwrap();
+        assert_eq!(coach.id, 2042);
+        assert_eq!(coach.time_on_team, "1 year 11 months");
+        assert_eq!(coach.maps_coached, 512);
     }
 }
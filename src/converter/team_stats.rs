@@ -0,0 +1,138 @@
+/*!
+This module defines how the map statistics of a team are parsed.
+*/
+use std::collections::HashMap;
+
+use crate::data::*;
+use crate::tl_extensions::*;
+use crate::ConvertInstance;
+use crate::{Error, Error::ConversionError};
+
+impl ConvertInstance for TeamStats {
+    fn convert<'a>(d: &'a tl::VDom<'a>) -> Result<TeamStats, Error> {
+        let root = d
+            .query_selector("div.stats-section")
+            .unwrap()
+            .next()
+            .ok_or(ConversionError("no stats-section node found"))?
+            .to_rich(d);
+        Ok(TeamStats {
+            id: get_id(d)?,
+            maps: get_maps(root)?,
+        })
+    }
+}
+
+/// Returns the team ID from the canonical link of the page.
+fn get_id(d: &tl::VDom) -> Result<u32, Error> {
+    for elem in d.query_selector("link[href]").unwrap() {
+        let link = elem.to_rich(d).get_attr_str("href").unwrap();
+        if link.contains("https://www.hltv.org/stats/teams/") {
+            return link
+                .split('/')
+                .find_map(|x| x.parse().ok())
+                .ok_or(ConversionError("error parsing team stats link tag"));
+        }
+    }
+    Err(ConversionError("couldn't find link tag with team ID"))
+}
+
+/// Returns the statistics of every listed map.
+fn get_maps(h: RichNode) -> Result<HashMap<Map, TeamMapStats>, Error> {
+    let mut result = HashMap::new();
+    for m in h.find("two-grid").find_all("col") {
+        let map: Map = match m.find("map-pool-map-name").inner_text() {
+            Some(x) => x.trim().to_string().into(),
+            None => continue,
+        };
+        result.insert(map, get_map_stats(m)?);
+    }
+    Ok(result)
+}
+
+/// Parses the label/value rows of a single map.
+fn get_map_stats(h: RichNode) -> Result<TeamMapStats, Error> {
+    let mut result = TeamMapStats::default();
+    let mut wdl = false;
+    for row in h.find_all("stats-row") {
+        let label = row
+            .child(0)
+            .and_then(|c| c.inner_text())
+            .unwrap_or_default();
+        let value = row
+            .child(1)
+            .and_then(|c| c.inner_text())
+            .unwrap_or_default();
+        let percent = || value.trim().trim_end_matches('%').parse::<f32>().ok();
+        match label.trim().to_lowercase().as_ref() {
+            "wins / draws / losses" => {
+                let v: Vec<u32> = value
+                    .split('/')
+                    .filter_map(|x| x.trim().parse().ok())
+                    .collect();
+                if v.len() != 3 {
+                    return Err(ConversionError(
+                        "wins / draws / losses has incorrect format",
+                    ));
+                }
+                (result.wins, result.draws, result.losses) = (v[0], v[1], v[2]);
+                wdl = true;
+            }
+            "win rate" => {
+                result.win_rate = percent().ok_or(ConversionError("can't parse win rate"))?
+            }
+            "total rounds played" => result.rounds_played = value.trim().parse().ok(),
+            "pick percentage" => result.pick_rate = percent(),
+            "ban percentage" => result.ban_rate = percent(),
+            _ => (),
+        }
+    }
+    match wdl {
+        true => Ok(result),
+        false => Err(ConversionError("no wins / draws / losses found for map")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    /// Tests if the map statistics of a team are parsed.
+    #[test]
+    pub fn team_maps() {
+        let input = include_str!("../testdata/teamStatsPages/navi_maps.html");
+        let dom = tl::parse(input, tl::ParserOptions::default()).unwrap();
+        let result = TeamStats::convert(&dom).unwrap();
+        assert_eq!(result.id, 4608);
+        assert_eq!(result.maps.len(), 5);
+        assert_eq!(
+            result.maps[&Map::Inferno],
+            TeamMapStats {
+                wins: 26,
+                draws: 0,
+                losses: 16,
+                win_rate: 61.9,
+                rounds_played: Some(1104),
+                pick_rate: Some(35.2),
+                ban_rate: Some(4.1),
+            }
+        );
+        let nuke = &result.maps[&Map::Nuke];
+        assert_eq!(nuke.played(), 9);
+        assert_eq!(nuke.pick_rate, None);
+        assert_eq!(result.maps[&Map::Anubis].wins, 7);
+        // maps unknown to the crate must not overwrite each other
+        assert_eq!(result.maps[&Map::Other("Thera".to_string())].losses, 3);
+        assert_eq!(result.maps[&Map::Other("Mills".to_string())].wins, 2);
+    }
+
+    /// Tests that a malformed win rate fails the conversion instead of defaulting to 0.
+    #[test]
+    pub fn malformed_win_rate() {
+        let input =
+            include_str!("../testdata/teamStatsPages/navi_maps.html").replace("61.9%", "n/a");
+        let dom = tl::parse(&input, tl::ParserOptions::default()).unwrap();
+        assert!(TeamStats::convert(&dom).is_err());
+    }
+}
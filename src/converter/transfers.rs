@@ -0,0 +1,102 @@
+/*!
+This module defines how the global transfer listing is parsed.
+*/
+use chrono::{TimeZone, Utc};
+
+use crate::data::*;
+use crate::tl_extensions::*;
+use crate::ConvertInstance;
+use crate::{Error, Error::ConversionError};
+
+impl ConvertInstance for Vec<Transfer> {
+    fn convert<'a>(d: &'a tl::VDom<'a>) -> Result<Vec<Transfer>, Error> {
+        let container = d
+            .query_selector("div.transfers-container")
+            .unwrap()
+            .next()
+            .ok_or(ConversionError("no div.transfers-container found"))?;
+        let mut result = Vec::new();
+        for h in container.to_rich(d).find_all("transfer-row") {
+            result.push(Transfer {
+                player: get_player(h)?,
+                from: get_team(h, "transfer-team-from"),
+                to: get_team(h, "transfer-team-to"),
+                date: get_date(h)?,
+                kind: h.get_attr_str("data-type").unwrap_or_default().into(),
+            });
+        }
+        Ok(result)
+    }
+}
+
+/// Returns the player who is transferred.
+fn get_player(h: RichNode) -> Result<Player, Error> {
+    let link = h.find("transfer-player").find_tag("a");
+    Ok(Player {
+        id: link
+            .get_attr_str("href")
+            .and_then(|x| x.split('/').nth(2)?.parse().ok())
+            .ok_or(ConversionError("couldn't parse transfer player ID"))?,
+        nickname: link
+            .inner_text()
+            .ok_or(ConversionError("no transfer player name found"))?,
+    })
+}
+
+/// Returns the team in the given column. Free agents don't have a team link.
+fn get_team(h: RichNode, class: &'static str) -> Option<TeamRef> {
+    let link = h.find(class).find_tag("a");
+    Some(TeamRef {
+        id: link.get_attr_str("href")?.split('/').nth(2)?.parse().ok()?,
+        name: link.inner_text()?.trim().to_string(),
+    })
+}
+
+fn get_date(h: RichNode) -> Result<chrono::DateTime<Utc>, Error> {
+    let timestamp: i64 = h
+        .find("transfer-date")
+        .get_attr("data-unix")?
+        .ok_or(ConversionError("no data-unix attribute"))?;
+    Utc.timestamp_opt(timestamp / 1000, 0)
+        .single()
+        .ok_or(ConversionError(
+            "data-unix attribute is not a valid timestamp",
+        ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    /// Tests if transfers, including moves to no team, are parsed.
+    #[test]
+    pub fn transfers() {
+        let input = include_str!("../testdata/transferPages/transfers.html");
+        let dom = tl::parse(input, tl::ParserOptions::default()).unwrap();
+        let result = Vec::<Transfer>::convert(&dom).unwrap();
+        assert_eq!(result.len(), 3);
+        assert_eq!(
+            result[1],
+            Transfer {
+                player: Player {
+                    id: 16947,
+                    nickname: "headtr1ck".to_string()
+                },
+                from: Some(TeamRef {
+                    id: 7020,
+                    name: "Spirit".to_string()
+                }),
+                to: Some(TeamRef {
+                    id: 11283,
+                    name: "Falcons".to_string()
+                }),
+                date: Utc.timestamp_opt(1658793600, 0).unwrap(),
+                kind: TransferType::Loan,
+            }
+        );
+        assert_eq!(result[0].kind, TransferType::Join);
+        assert_eq!(result[2].to, None);
+        assert_eq!(result[2].kind, TransferType::Bench);
+    }
+}
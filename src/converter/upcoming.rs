@@ -1,5 +1,5 @@
 use chrono::DateTime;
-use chrono::NaiveDateTime;
+use chrono::TimeZone;
 use chrono::Utc;
 
 use crate::data::*;
@@ -44,10 +44,9 @@ fn parse_date(h: RichNode) -> Result<DateTime<Utc>, Error> {
     let time: i64 = h
         .get_attr::<i64>("data-zonedgrouping-entry-unix")?
         .ok_or(ConversionError("time is not set in div"))?;
-    Ok(DateTime::<Utc>::from_utc(
-        NaiveDateTime::from_timestamp(time / 1000, 0),
-        Utc,
-    ))
+    Utc.timestamp_opt(time / 1000, 0)
+        .single()
+        .ok_or(ConversionError("time is not a valid timestamp"))
 }
 
 /// Parses the match ID from the given root node
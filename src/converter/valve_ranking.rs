@@ -0,0 +1,79 @@
+/*!
+This module defines how the Valve regional standings are parsed.
+*/
+use crate::converter::ranking::get_entry;
+use crate::data::*;
+use crate::tl_extensions::*;
+use crate::ConvertInstance;
+use crate::{Error, Error::ConversionError};
+
+impl ConvertInstance for Vec<ValveStanding> {
+    fn convert<'a>(d: &'a tl::VDom<'a>) -> Result<Vec<ValveStanding>, Error> {
+        let root = d
+            .query_selector("div.ranking")
+            .unwrap()
+            .next()
+            .ok_or(ConversionError("no div.ranking container found"))?
+            .to_rich(d);
+        let region = get_region(root);
+        let mut result = Vec::new();
+        for h in root.find_all("ranked-team") {
+            let entry = get_entry(h)?;
+            result.push(ValveStanding {
+                region,
+                rank: entry.rank,
+                team: entry.team,
+                points: entry.points,
+                players: entry.players,
+            });
+        }
+        Ok(result)
+    }
+}
+
+/// Returns the region from a header like `Valve Regional Standings for Europe on ...`.
+fn get_region(h: RichNode) -> ValveRegion {
+    h.find("regional-ranking-header")
+        .inner_text()
+        .and_then(|x| Some(x.split(" for ").nth(1)?.split(" on ").next()?.to_string()))
+        .unwrap_or_default()
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    /// Tests if the standings of a region are parsed.
+    #[test]
+    pub fn europe() {
+        let input = include_str!("../testdata/valveRankingPages/europe.html");
+        let dom = tl::parse(input, tl::ParserOptions::default()).unwrap();
+        let result = Vec::<ValveStanding>::convert(&dom).unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(
+            result[0],
+            ValveStanding {
+                region: ValveRegion::Europe,
+                rank: 1,
+                team: TeamRef {
+                    id: 4608,
+                    name: "Natus Vincere".to_string()
+                },
+                points: 1986,
+                players: vec![
+                    Player {
+                        id: 13915,
+                        nickname: "jL".to_string()
+                    },
+                    Player {
+                        id: 18987,
+                        nickname: "b1t".to_string()
+                    },
+                ],
+            }
+        );
+        assert_eq!(result[1].points, 1951);
+    }
+}
@@ -3,10 +3,11 @@ Types for esports data from HLTV.
 
 
 */
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
+use std::collections::HashMap;
 
 /// Type of event. At this moment either LAN or online.
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum EventType {
     LAN,
     Online,
@@ -17,13 +18,18 @@ pub enum EventType {
 #[derive(Debug, PartialEq)]
 pub enum MatchFormat {
     Bo1,
+    /// Two maps are played, so the match can end in a `1-1` draw.
+    Bo2,
     Bo3,
     Bo5,
     Bo7,
+    /// The format couldn't be determined, e.g. because of a forfeit or an
+    /// unusual page layout.
+    Unknown,
 }
 
 /// Basic player information.
-#[derive(Default, Debug, PartialEq, Clone)]
+#[derive(Default, Debug, PartialEq, Eq, Hash, Clone)]
 pub struct Player {
     pub id: u32,
     pub nickname: String,
@@ -54,8 +60,17 @@ impl Team {
     }
 }
 
+/// Reference to a team by ID and name. Used where HLTV doesn't display a team logo.
+#[derive(Debug, PartialEq, Clone)]
+pub struct TeamRef {
+    /// HLTV-associated ID (found in the URL of team page).
+    pub id: u32,
+    /// Name of the team.
+    pub name: String,
+}
+
 /// Basic information about a team.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct Event {
     /// HLTV-associated ID (found in the URL of the event page).
     pub id: u32,
@@ -65,6 +80,7 @@ pub struct Event {
 
 /// Contains detailed information about an event. Corresponds to data found on [HLTV's event
 /// page](https://www.hltv.org/events/6345/blast-premier-spring-final-2022).
+#[derive(Debug, PartialEq)]
 pub struct EventDetails {
     /// HLTV-associated ID (found in the URL of the event page).
     pub id: u32,
@@ -76,24 +92,598 @@ pub struct EventDetails {
     pub end_date: DateTime<Utc>,
     /// Price pool of the event. Can be a USD figure, or guaranteed spots in another tournament.
     pub price_pool: String,
+    /// Prize money in USD, if the [`price_pool`][EventDetails::price_pool] starts with a
+    /// USD figure.
+    pub prize: Option<u32>,
+    /// Location of the event, e.g. `Antwerp, Belgium` or `Europe (Online)`.
+    pub location: Option<String>,
+    /// `None` if the page doesn't say whether the event is played on LAN or online.
+    pub event_type: Option<EventType>,
+    /// Number of participating teams. `None` if not yet announced.
+    pub teams: Option<u32>,
+    /// Group stages, Swiss stages and playoff brackets, in the order listed on the page.
+    pub stages: Vec<Stage>,
+}
+
+/// A stage of an event, containing either group tables or a bracket.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Stage {
+    /// Name of the stage, e.g. `Legends Stage` or `Playoffs`.
+    pub name: String,
+    pub kind: StageKind,
+    /// Group tables of the stage. A Swiss stage consists of a single group.
+    pub groups: Vec<Group>,
+    /// Bracket slots of the stage, ordered by round. Empty for group stages.
+    pub slots: Vec<BracketSlot>,
 }
 
-/// Contains extensive information about a team.
+/// Format of an event stage.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub enum StageKind {
+    /// Round-robin or GSL groups.
+    #[default]
+    Groups,
+    /// Teams with the same record play each other, until they reach a fixed
+    /// number of wins or losses.
+    Swiss,
+    /// Single or double elimination bracket.
+    Bracket,
+}
+
+/// A group table within a stage.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Group {
+    /// Name of the group, e.g. `Group A`.
+    pub name: String,
+    pub standings: Vec<GroupStanding>,
+}
+
+/// A team's row in a group table.
+#[derive(Debug, PartialEq, Clone)]
+pub struct GroupStanding {
+    /// Position within the group.
+    pub position: u32,
+    pub team: TeamRef,
+    pub wins: u32,
+    pub losses: u32,
+    /// Difference between rounds won and rounds lost.
+    pub round_diff: i32,
+}
+
+/// A match in a playoff bracket. Teams and match are `None` if they are not
+/// determined yet.
+#[derive(Debug, PartialEq, Clone)]
+pub struct BracketSlot {
+    /// Name of the bracket round, e.g. `Semi-finals`.
+    pub round: String,
+    /// Match ID, to be used with [`crate::get_match`].
+    pub match_id: Option<u32>,
+    pub team1: Option<TeamRef>,
+    pub team2: Option<TeamRef>,
+    /// Maps won by each team, if the match has been played.
+    pub score: Option<MatchScore>,
+    pub winner: WhichTeam,
+}
+
+/// Summary of an event as listed on [HLTV's events page](https://www.hltv.org/events)
+/// and the [event archive](https://www.hltv.org/events/archive).
+#[derive(Debug, PartialEq, Clone)]
+pub struct EventSummary {
+    /// HLTV-associated ID (found in the URL of the event page).
+    pub id: u32,
+    pub name: String,
+    pub start_date: Option<DateTime<Utc>>,
+    /// Date when the event finishes. Same as the start date for single-day events.
+    pub end_date: Option<DateTime<Utc>>,
+    /// Prize money in USD, if listed as a USD figure.
+    pub prize: Option<u32>,
+    /// Number of participating teams. `None` if not yet announced.
+    pub teams: Option<u32>,
+    /// Location of the event, e.g. `Cologne, Germany` or `Europe (Online)`.
+    pub location: Option<String>,
+    /// `None` if the listing doesn't say whether the event is played on LAN or
+    /// online, which is the case for ongoing events.
+    pub event_type: Option<EventType>,
+    /// Whether the event is currently running.
+    pub ongoing: bool,
+}
+
+/// Contains extensive information about a team. Corresponds to data found on [HLTV's
+/// team page](https://www.hltv.org/team/4608/natus-vincere).
 #[derive(Debug)]
 pub struct TeamPage {
     /// Team ID according to HLTV team page URL.
     pub id: u32,
     /// Name of the team.
     pub name: String,
-    /// HLTV ranking of this team.
-    pub ranking: u32,
+    /// HLTV ranking of this team. `None` if the team is unranked.
+    pub ranking: Option<u32>,
+    /// Best HLTV ranking within the [`ranking_history`][TeamPage::ranking_history]. The
+    /// history only covers the period shown on the team page, so this is not necessarily
+    /// the team's all-time peak.
+    pub peak_ranking: Option<u32>,
+    /// Number of weeks the core of the team has been in the top 30 of the HLTV ranking.
+    pub weeks_in_top30: Option<u32>,
+    /// Weekly HLTV ranking of the team, oldest first.
+    pub ranking_history: Vec<RankingPoint>,
+    /// Country or region of the team, e.g. `Ukraine` or `Europe`.
+    pub country: Option<String>,
+    /// Links to the team's social media profiles and website.
+    pub social: Vec<SocialLink>,
     /// All known players of the team. Can be less than five, or even more than five (in the case
     /// of 6-man rosters).
     pub players: Vec<Player>,
+    /// Detailed roster including benched and inactive players. Doesn't contain the coach.
+    pub roster: Vec<RosterMember>,
+    /// Upcoming matches of the team. The match format and stars are not listed on the
+    /// team page, so they are set to [`MatchFormat::Unknown`] and `0`.
+    pub upcoming: Vec<UpcomingMatch>,
+    /// Recent results of the team. The match format is not listed on the team page,
+    /// so it is set to [`MatchFormat::Unknown`].
+    pub results: Vec<MatchResult>,
+    /// Placements of the team at past events, most recent first.
+    pub achievements: Vec<Achievement>,
+    /// Coach of the team, if any.
+    pub coach: Option<Coach>,
     /// URL of the logo (hltv cdn).
     pub logo: String,
 }
 
+/// Placement of a team at a past event.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Achievement {
+    pub event: Event,
+    pub placement: Placement,
+    /// Start date of the event, if listed on the team's event history.
+    pub date: Option<DateTime<Utc>>,
+    /// Prize money won by the team in USD, if known.
+    pub prize: Option<u32>,
+}
+
+/// A placement at an event. Shared placements (e.g. `3-4th`) are represented as a range,
+/// single placements have the same `best` and `worst` value.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Placement {
+    pub best: u32,
+    pub worst: u32,
+}
+
+impl std::str::FromStr for Placement {
+    type Err = ();
+
+    /// Parses placements like `1st`, `2nd` or `5-8th`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = |x: &str| -> Result<u32, ()> {
+            x.trim()
+                .trim_end_matches(char::is_alphabetic)
+                .parse()
+                .map_err(|_| ())
+        };
+        match s.split_once('-') {
+            Some((best, worst)) => Ok(Placement {
+                best: digits(best)?,
+                worst: digits(worst)?,
+            }),
+            None => {
+                let place = digits(s)?;
+                Ok(Placement {
+                    best: place,
+                    worst: place,
+                })
+            }
+        }
+    }
+}
+
+/// Position of a team in a single week of the HLTV ranking.
+#[derive(Debug, PartialEq, Clone)]
+pub struct RankingPoint {
+    /// Date of the ranking update.
+    pub date: NaiveDate,
+    /// Position in the ranking.
+    pub rank: u32,
+}
+
+/// A link to a social media profile or website.
+#[derive(Debug, PartialEq, Clone)]
+pub struct SocialLink {
+    pub platform: SocialPlatform,
+    pub url: String,
+}
+
+/// Social media platforms linked on HLTV profiles.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub enum SocialPlatform {
+    /// Any other link, usually the team's or player's own website.
+    #[default]
+    Website,
+    Twitter,
+    Facebook,
+    Instagram,
+    Twitch,
+    YouTube,
+}
+
+impl From<String> for SocialPlatform {
+    /// Determines the platform from the host of the given URL. Subdomains like `www.` or
+    /// `m.` are allowed, other hosts that merely contain the domain are not.
+    fn from(url: String) -> Self {
+        use SocialPlatform::*;
+        let host = match url_host(&url) {
+            Some(x) => x,
+            None => return Website,
+        };
+        let is = |domain: &str| is_host(&host, domain);
+        if is("twitter.com") || is("x.com") {
+            Twitter
+        } else if is("facebook.com") {
+            Facebook
+        } else if is("instagram.com") {
+            Instagram
+        } else if is("twitch.tv") {
+            Twitch
+        } else if is("youtube.com") {
+            YouTube
+        } else {
+            Website
+        }
+    }
+}
+
+/// Returns the lowercase host of the given URL, if it can be parsed.
+fn url_host(url: &str) -> Option<String> {
+    Some(reqwest::Url::parse(url).ok()?.host_str()?.to_lowercase())
+}
+
+/// Whether `host` is `domain` itself or one of its subdomains, e.g. `www.` or `m.`.
+fn is_host(host: &str, domain: &str) -> bool {
+    host == domain || host.ends_with(&format!(".{}", domain))
+}
+
+/// A member of a team's roster, as listed in the roster table of the team page.
+#[derive(Debug, PartialEq, Clone)]
+pub struct RosterMember {
+    pub player: Player,
+    /// Country name of the member's flag, e.g. `Ukraine`.
+    pub country: Option<String>,
+    /// Role of the member in the team.
+    pub status: RosterStatus,
+    /// Time spent on the team as displayed by HLTV, e.g. `2 years 6 months`.
+    pub time_on_team: String,
+    /// Number of maps played for the team.
+    pub maps_played: u32,
+}
+
+/// Coach of a team, as listed in the coach table of the team page.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Coach {
+    /// Coach ID according to HLTV coach page URL (`hltv.org/coach/{id}/...`). This is
+    /// not a player ID.
+    pub id: u32,
+    pub nickname: String,
+    /// Country name of the coach's flag, e.g. `Ukraine`.
+    pub country: Option<String>,
+    /// Time spent on the team as displayed by HLTV, e.g. `2 years 6 months`.
+    pub time_on_team: String,
+    /// Number of maps coached for the team.
+    pub maps_coached: u32,
+}
+
+/// Role of a member in a team's roster.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub enum RosterStatus {
+    #[default]
+    Unknown,
+    /// Part of the active lineup.
+    Starter,
+    /// Plays for the team temporarily.
+    Substitute,
+    /// Still under contract, but not part of the active lineup.
+    Benched,
+    /// Not playing, e.g. due to a break or health reasons.
+    Inactive,
+}
+
+impl From<String> for RosterStatus {
+    fn from(s: String) -> Self {
+        use RosterStatus::*;
+        match s.trim().to_lowercase().as_ref() {
+            "starter" | "player" => Starter,
+            "substitute" | "stand-in" => Substitute,
+            "benched" => Benched,
+            "inactive" => Inactive,
+            _ => Unknown,
+        }
+    }
+}
+
+/// Map statistics of a team. Corresponds to data found on [HLTV's team map stats
+/// page](https://www.hltv.org/stats/teams/maps/4608/natus-vincere).
+#[derive(Debug, PartialEq)]
+pub struct TeamStats {
+    /// Team ID according to HLTV team page URL.
+    pub id: u32,
+    /// Statistics of every map the team has played in the selected time frame.
+    pub maps: HashMap<Map, TeamMapStats>,
+}
+
+/// Statistics of a team on a single map.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct TeamMapStats {
+    pub wins: u32,
+    pub draws: u32,
+    pub losses: u32,
+    /// Percentage of maps won.
+    pub win_rate: f32,
+    /// Total number of rounds played on this map.
+    pub rounds_played: Option<u32>,
+    /// Percentage of the team's matches in which they picked this map.
+    pub pick_rate: Option<f32>,
+    /// Percentage of the team's matches in which they banned this map.
+    pub ban_rate: Option<f32>,
+}
+
+impl TeamMapStats {
+    /// Number of times the map was played.
+    pub fn played(&self) -> u32 {
+        self.wins + self.draws + self.losses
+    }
+}
+
+/// Contains profile information about a player. Corresponds to data found on [HLTV's
+/// player page](https://www.hltv.org/player/7998/s1mple).
+#[derive(Debug, PartialEq)]
+pub struct PlayerPage {
+    /// Player ID according to HLTV player page URL.
+    pub id: u32,
+    /// In-game name of the player.
+    pub nickname: String,
+    /// Full name of the player.
+    pub real_name: Option<String>,
+    /// Age in years.
+    pub age: Option<u32>,
+    /// Country name of the player's flag, e.g. `Ukraine`.
+    pub country: Option<String>,
+    /// Team the player currently plays for. `None` for free agents.
+    pub team: Option<TeamRef>,
+    /// URL of the bodyshot picture (hltv cdn).
+    pub image: Option<String>,
+    /// Links to the player's social media profiles.
+    pub social: Vec<SocialLink>,
+    /// Headline statistics over the past 3 months.
+    pub stats: PlayerSummaryStats,
+    /// Placements in HLTV's yearly top 20 player ranking, most recent first.
+    pub top20: Vec<Top20Placement>,
+    /// Teams the player has been part of, most recent first.
+    pub team_history: Vec<TeamMembership>,
+}
+
+/// A period in which a player was part of a team. HLTV only lists the month of
+/// joining and leaving, so the dates are set to the first day of that month.
+#[derive(Debug, PartialEq, Clone)]
+pub struct TeamMembership {
+    pub team: TeamRef,
+    pub joined: Option<NaiveDate>,
+    /// Month the player left the team. `None` if the player is still on the team.
+    pub left: Option<NaiveDate>,
+    /// Role of the player in the team, e.g. [`RosterStatus::Substitute`] for stand-ins.
+    pub role: RosterStatus,
+}
+
+/// A roster move listed on [HLTV's transfer page](https://www.hltv.org/transfers).
+#[derive(Debug, PartialEq, Clone)]
+pub struct Transfer {
+    pub player: Player,
+    /// Team the player left. `None` if the player was a free agent.
+    pub from: Option<TeamRef>,
+    /// Team the player joined. `None` if the player became a free agent or retired.
+    pub to: Option<TeamRef>,
+    pub date: DateTime<Utc>,
+    pub kind: TransferType,
+}
+
+/// Type of a roster move.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub enum TransferType {
+    #[default]
+    Unknown,
+    /// Player joins a team permanently.
+    Join,
+    /// Player leaves a team, usually becoming a free agent.
+    Leave,
+    /// Player plays for another team on loan.
+    Loan,
+    /// Player is moved to the bench.
+    Bench,
+    /// Player is set inactive.
+    Inactive,
+    Retire,
+}
+
+impl From<String> for TransferType {
+    fn from(s: String) -> Self {
+        use TransferType::*;
+        match s.trim().to_lowercase().as_ref() {
+            "join" | "joins" | "signing" => Join,
+            "leave" | "leaves" | "release" => Leave,
+            "loan" => Loan,
+            "bench" | "benched" => Bench,
+            "inactive" => Inactive,
+            "retire" | "retired" | "retirement" => Retire,
+            _ => Unknown,
+        }
+    }
+}
+
+/// Headline statistics shown on a player's profile. Fields are `None` if
+/// HLTV doesn't show the figure, e.g. for players without recent matches.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct PlayerSummaryStats {
+    /// HLTV 2.0 rating.
+    pub rating: Option<f32>,
+    /// Average kills per round.
+    pub kills_per_round: Option<f32>,
+    /// Average deaths per round.
+    pub deaths_per_round: Option<f32>,
+    /// Average damage per round.
+    pub adr: Option<f32>,
+    /// Percentage of kills that were headshots.
+    pub headshot_percentage: Option<f32>,
+    /// Number of maps the statistics are based on.
+    pub maps_played: Option<u32>,
+}
+
+/// Placement of a player in HLTV's top 20 players of a year.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Top20Placement {
+    pub year: u32,
+    /// Position in the ranking, from 1 to 20.
+    pub rank: u32,
+}
+
+/// Statistical profile of a player. Corresponds to data found on [HLTV's
+/// player stats page](https://www.hltv.org/stats/players/7998/s1mple).
+#[derive(Debug, PartialEq)]
+pub struct PlayerStats {
+    /// Player ID according to HLTV player page URL.
+    pub id: u32,
+    /// Totals over all matches matching the selected filters.
+    pub overall: PlayerOverallStats,
+    /// Rating per calendar year, most recent first. Taken from the career subpage.
+    pub years: Vec<PlayerYearStats>,
+    /// Statistics on every map the player has played. Taken from the maps subpage.
+    pub maps: HashMap<Map, PlayerMapStats>,
+}
+
+/// Career totals and averages of a player.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct PlayerOverallStats {
+    pub kills: u32,
+    pub deaths: u32,
+    /// Percentage of kills that were headshots.
+    pub headshot_percentage: f32,
+    pub kd_ratio: f32,
+    /// Average damage per round.
+    pub adr: Option<f32>,
+    pub maps_played: u32,
+    pub rounds_played: u32,
+    pub kills_per_round: f32,
+    pub assists_per_round: f32,
+    pub deaths_per_round: f32,
+    /// HLTV 2.0 rating.
+    pub rating: Option<f32>,
+}
+
+/// Statistics of a player in a single year.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PlayerYearStats {
+    pub year: u32,
+    /// HLTV 2.0 rating. `None` if the player has no rated maps in that year.
+    pub rating: Option<f32>,
+    pub maps_played: u32,
+}
+
+/// Statistics of a player on a single map.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PlayerMapStats {
+    pub maps_played: u32,
+    /// Difference between kills and deaths on this map.
+    pub kd_diff: i32,
+    /// HLTV 2.0 rating.
+    pub rating: f32,
+}
+
+/// A team's entry in a weekly [HLTV ranking](https://www.hltv.org/ranking/teams).
+#[derive(Debug, PartialEq, Clone)]
+pub struct RankingEntry {
+    /// Position in the ranking.
+    pub rank: u32,
+    pub team: TeamRef,
+    /// URL of the logo (hltv cdn).
+    pub logo: Option<String>,
+    pub points: u32,
+    /// Positions gained (positive) or lost (negative) since the previous ranking.
+    /// `None` if the team is new to the ranking.
+    pub change: Option<i32>,
+    /// Lineup the ranking points were awarded to.
+    pub players: Vec<Player>,
+}
+
+/// A team's entry in Valve's regional standings, which are used for tournament
+/// invites. Mirrored on [HLTV](https://www.hltv.org/valve-ranking/teams).
+#[derive(Debug, PartialEq, Clone)]
+pub struct ValveStanding {
+    /// Region of the standings this entry belongs to.
+    pub region: ValveRegion,
+    /// Position within the region.
+    pub rank: u32,
+    pub team: TeamRef,
+    pub points: u32,
+    /// Roster the points were awarded to.
+    pub players: Vec<Player>,
+}
+
+/// Regions of the Valve standings.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum ValveRegion {
+    /// All teams regardless of region.
+    #[default]
+    Global,
+    Europe,
+    Americas,
+    Asia,
+}
+
+impl From<String> for ValveRegion {
+    fn from(s: String) -> Self {
+        use ValveRegion::*;
+        match s.trim().to_lowercase().as_ref() {
+            "europe" => Europe,
+            "americas" => Americas,
+            "asia" => Asia,
+            _ => Global,
+        }
+    }
+}
+
+impl std::fmt::Display for ValveRegion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValveRegion::Global => write!(f, "Global"),
+            ValveRegion::Europe => write!(f, "Europe"),
+            ValveRegion::Americas => write!(f, "Americas"),
+            ValveRegion::Asia => write!(f, "Asia"),
+        }
+    }
+}
+
+/// A row of the [HLTV player leaderboard](https://www.hltv.org/stats/players), which
+/// ranks players by rating.
+#[derive(Debug, PartialEq, Clone)]
+pub struct LeaderboardEntry {
+    pub player: Player,
+    /// Country name of the player's flag, e.g. `Ukraine`.
+    pub country: Option<String>,
+    /// Teams the player played for in the selected time frame.
+    pub teams: Vec<TeamRef>,
+    pub maps_played: u32,
+    pub rounds_played: u32,
+    /// Difference between kills and deaths.
+    pub kd_diff: i32,
+    pub kd_ratio: f32,
+    /// HLTV 2.0 rating.
+    pub rating: f32,
+}
+
+/// Hits of a free-text search on HLTV, grouped by type. Only the name and ID are
+/// listed, so use [`crate::get_team`], [`crate::get_player`] etc. for details.
+#[derive(Debug, Default, PartialEq)]
+pub struct SearchResults {
+    pub teams: Vec<TeamRef>,
+    pub players: Vec<Player>,
+    pub events: Vec<Event>,
+}
+
 /// Contains a summary of an upcoming match ([reference](https://www.hltv.org/matches)).
 #[derive(Debug)]
 pub struct UpcomingMatch {
@@ -163,15 +753,203 @@ pub struct MatchPage {
     pub date: DateTime<Utc>,
     /// Format of the match. The format determines how many maps a team needs to win a match.
     pub format: MatchFormat,
-    /// A match score. In case of bo1, either `1-0` or `0-1`. For bo3 it's `2-0`, `2-1`
-    /// and so on.
+    /// A match score. In case of bo1, either `1-0` or `0-1` (`0-0` while live). For bo3
+    /// it's `2-0`, `2-1` and so on.
     pub score: Option<MatchScore>,
+    /// The round score of a bo1, e.g. `13-7`. `None` for every other format, use
+    /// [`maps`][MatchPage::maps] instead.
+    pub round_score: Option<RoundScore>,
     /// A collection of map-specific scores. Up to 7 maps can be played per map. Empty
     /// if the game hasn't started yet. Contains partial results if maps have been played
     /// but the match hasn't fully concluded yet (which can be the case for bo3+).
     pub maps: Vec<MapScore>,
     /// Performance of players over all maps.
     pub stats: Vec<Performance>,
+    /// Streams, VODs, demos and highlights linked on the match page.
+    pub media: MatchMedia,
+    /// Announced lineups of both teams. Also available before the match starts,
+    /// unlike [`stats`][MatchPage::stats]. Empty if the teams aren't known yet.
+    pub lineups: Vec<Lineup>,
+    /// Summary of previous meetings between both teams, if HLTV lists any.
+    pub head_to_head: Option<HeadToHead>,
+    /// Recent matches of both teams, regardless of the opponent.
+    pub past_matches: Vec<PastMatches>,
+    /// Betting odds of the providers listed by HLTV. Empty if no odds are shown.
+    pub odds: Vec<Odds>,
+}
+
+/// The lineup of one team in a match.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Lineup {
+    /// Which team of the match this lineup belongs to.
+    pub team: WhichTeam,
+    /// Players in the order displayed by HLTV.
+    pub players: Vec<LineupPlayer>,
+}
+
+/// A player that is part of a [`Lineup`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct LineupPlayer {
+    pub player: Player,
+    /// Country name of the player's flag, e.g. `Denmark`.
+    pub country: Option<String>,
+    /// True if HLTV marks the player as a stand-in for this match.
+    pub stand_in: bool,
+}
+
+/// Previous meetings of both teams of a match. Team 1 and team 2 always refer to
+/// the teams of the [`MatchPage`] this belongs to.
+#[derive(Debug, PartialEq, Clone)]
+pub struct HeadToHead {
+    /// Number of maps won by team 1.
+    pub team1_wins: u32,
+    /// Number of maps won by team 2.
+    pub team2_wins: u32,
+    /// Number of maps that went into overtime.
+    pub overtimes: u32,
+    /// The individual maps of past meetings, most recent first.
+    pub meetings: Vec<PastMeeting>,
+}
+
+/// A single map played in a previous meeting of the two teams.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PastMeeting {
+    /// ID of the match this map was played in.
+    pub id: u32,
+    pub date: DateTime<Utc>,
+    pub event: Event,
+    pub map: Map,
+    /// Winner of the map.
+    pub winner: WhichTeam,
+    /// Round score, ordered like the teams of the current match.
+    pub score: RoundScore,
+}
+
+/// The most recent matches of one team of a match.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PastMatches {
+    /// Which team of the match these results belong to.
+    pub team: WhichTeam,
+    /// Results, most recent first.
+    pub matches: Vec<PastMatch>,
+}
+
+/// Summary of a recently played match from the perspective of one team.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PastMatch {
+    /// ID of the past match.
+    pub id: u32,
+    /// Team ID of the opponent.
+    pub opponent_id: u32,
+    /// Name of the opponent.
+    pub opponent: String,
+    /// Maps (or rounds for bo1s) won by the team.
+    pub score: u32,
+    /// Maps (or rounds for bo1s) won by the opponent.
+    pub opponent_score: u32,
+    /// True if the team won the match.
+    pub won: bool,
+}
+
+/// Decimal betting odds of a single provider for a match.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Odds {
+    /// Name of the betting provider, e.g. `GG.BET`.
+    pub provider: String,
+    /// Decimal odds of team 1 winning the match.
+    pub team1: f32,
+    /// Decimal odds of team 2 winning the match.
+    pub team2: f32,
+}
+
+/// Collection of media links found on a match page. All collections are empty if
+/// HLTV doesn't list any media for the match.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct MatchMedia {
+    /// Broadcasts of the match. Usually only listed before and during the match.
+    pub streams: Vec<Stream>,
+    /// Recordings of the broadcast, typically one per map.
+    pub vods: Vec<Vod>,
+    /// GOTV demo downloads.
+    pub demos: Vec<Demo>,
+    /// Highlight clips of the match.
+    pub highlights: Vec<Highlight>,
+}
+
+/// A live broadcast of a match.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Stream {
+    /// Platform the stream is hosted on.
+    pub platform: StreamPlatform,
+    /// Name of the stream as displayed by HLTV, e.g. `BLAST Premier`.
+    pub name: String,
+    /// Link to the embedded stream.
+    pub url: String,
+    /// Country name of the language flag, e.g. `United Kingdom`.
+    pub language: Option<String>,
+    /// Number of current viewers, if HLTV tracks them.
+    pub viewers: Option<u32>,
+}
+
+/// A recording of a broadcast.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Vod {
+    /// Platform the recording is hosted on.
+    pub platform: StreamPlatform,
+    /// Title as displayed by HLTV, e.g. `Watch map 1 - Dust2`.
+    pub title: String,
+    /// Link to the embedded recording.
+    pub url: String,
+}
+
+/// A downloadable GOTV demo.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Demo {
+    /// Absolute download link of the demo archive.
+    pub url: String,
+}
+
+/// A short clip of a match highlight.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Highlight {
+    /// Title of the clip, e.g. `ZywOo 4k vs Astralis`.
+    pub title: String,
+    /// Link to the embedded clip.
+    pub url: String,
+}
+
+/// Platforms that host streams and VODs.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub enum StreamPlatform {
+    #[default]
+    Other,
+    Twitch,
+    YouTube,
+    Kick,
+    Facebook,
+}
+
+impl From<String> for StreamPlatform {
+    /// Determines the platform from the host of the given URL, like [`SocialPlatform`].
+    fn from(url: String) -> Self {
+        use StreamPlatform::*;
+        let host = match url_host(&url) {
+            Some(x) => x,
+            None => return Other,
+        };
+        let is = |domain: &str| is_host(&host, domain);
+        if is("twitch.tv") {
+            Twitch
+        } else if is("youtube.com") || is("youtu.be") {
+            YouTube
+        } else if is("kick.com") {
+            Kick
+        } else if is("facebook.com") {
+            Facebook
+        } else {
+            Other
+        }
+    }
 }
 
 /// Current status of a match.
@@ -186,7 +964,7 @@ pub enum MatchStatus {
 }
 
 /// Refers to either the first or second team in a match, according to HLTV order.
-#[derive(Default, Debug, PartialEq)]
+#[derive(Default, Debug, PartialEq, Clone)]
 pub enum WhichTeam {
     #[default]
     None,
@@ -198,7 +976,7 @@ pub enum WhichTeam {
 
 /// A match score refers to the number of won maps of both team 1 and team 2.
 /// Examples are `1-0`, `2-1`, `1-3`, etc.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct MatchScore {
     /// The number of maps won by the left team.
     pub team1: u32,
@@ -206,6 +984,15 @@ pub struct MatchScore {
     pub team2: u32,
 }
 
+/// The number of rounds won by team 1 and team 2, e.g. `13-11` or `19-17`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct RoundScore {
+    /// The number of rounds won by the left team.
+    pub team1: u32,
+    /// The number of rounds won by the right team.
+    pub team2: u32,
+}
+
 /// Represents the result of a single map. Examples are: `16-14`, `10-16`, `19-17`
 #[derive(Debug, PartialEq)]
 pub struct MapScore {
@@ -214,11 +1001,19 @@ pub struct MapScore {
     pub team1: u32,
     /// Number of rounds won by team 2.
     pub team2: u32,
+    /// ID of the detailed map statistics (found in the URL `stats/matches/mapstatsid/{id}/...`).
+    /// Can be fetched with [`get_map_stats`][crate::get_map_stats].
+    pub stats_id: Option<u32>,
 }
 
 impl MapScore {
     pub fn new(map: Map, team1: u32, team2: u32) -> Self {
-        MapScore { map, team1, team2 }
+        MapScore {
+            map,
+            team1,
+            team2,
+            stats_id: None,
+        }
     }
 }
 
@@ -241,8 +1036,185 @@ pub struct Stats {
     pub rating: f32,
 }
 
-/// All CSGO maps that are listed on HLTV
+/// Contains detailed statistics of a single played map. Corresponds to data found on
+/// [HLTV's map stats page](https://www.hltv.org/stats/matches/mapstatsid/114121/astralis-vs-vitality).
+#[derive(Debug, PartialEq)]
+pub struct MapStatsPage {
+    /// ID of the map stats page.
+    pub id: u32,
+    /// ID of the match this map was played in, if linked.
+    pub match_id: Option<u32>,
+    pub map: Map,
+    /// Time when the map was played.
+    pub date: DateTime<Utc>,
+    /// Event at which this map was played.
+    pub event: Event,
+    /// First team, according to HLTV's display order.
+    pub team1: Option<Team>,
+    /// Second team, according to HLTV's display order.
+    pub team2: Option<Team>,
+    /// Final round score of the map.
+    pub score: RoundScore,
+    /// Round-by-round history, including overtime rounds.
+    pub rounds: Vec<Round>,
+    /// Performance of players on this map. Players of team 1 come first.
+    pub stats: Vec<DetailedPerformance>,
+}
+
+/// Outcome of a single round.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Round {
+    /// Number of the round, starting at 1.
+    pub number: u32,
+    /// Team that won the round.
+    pub winner: WhichTeam,
+    /// Side of the team that won the round.
+    pub side: Side,
+    /// How the round was won.
+    pub win_type: RoundWinType,
+    /// Score after this round.
+    pub score: RoundScore,
+}
+
+/// The side a team plays on.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Side {
+    CT,
+    T,
+}
+
+/// The different ways a round can be won.
+#[derive(Debug, PartialEq, Clone)]
+pub enum RoundWinType {
+    /// All players of the opposing team were killed.
+    Elimination,
+    /// The bomb exploded.
+    BombExploded,
+    /// The bomb was defused.
+    BombDefused,
+    /// The round timer ran out before the bomb was planted.
+    TimeRanOut,
+}
+
+/// A tuple of a specific players detailed map performance.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct DetailedPerformance(pub Player, pub DetailedStats);
+
+/// Extended collection of performance metrics of a player, as found on
+/// HLTV's stats pages.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct DetailedStats {
+    /// Total kills.
+    pub kills: u32,
+    /// Kills that were headshots.
+    pub headshots: u32,
+    /// Total assists.
+    pub assists: u32,
+    /// Assists by flashing the killed opponent.
+    pub flash_assists: u32,
+    /// Total deaths.
+    pub deaths: u32,
+    /// Percentage of rounds with either kill, assisst, support or trade.
+    pub kast: f32,
+    /// Average damage per round.
+    pub adr: f32,
+    /// Difference between opening kills and opening deaths.
+    pub first_kills_diff: i32,
+    /// HLTV 2.0 rating.
+    pub rating: f32,
+}
+
+/// Contains the kill matrices of a single map. Corresponds to data found on [HLTV's
+/// performance page](https://www.hltv.org/stats/matches/performance/mapstatsid/114121/astralis-vs-vitality).
+#[derive(Debug, PartialEq)]
+pub struct PerformancePage {
+    /// ID of the map stats page this performance page belongs to.
+    pub id: u32,
+    /// Kills between players, regardless of weapon or situation.
+    pub all: KillMatrix,
+    /// Opening kills of a round between players.
+    pub first_kills: KillMatrix,
+    /// Kills with the AWP between players.
+    pub awp_kills: KillMatrix,
+}
+
+/// Kills between every pair of opposing players. Keys are tuples of a player of
+/// team 1 and a player of team 2.
 #[derive(Debug, Default, PartialEq)]
+pub struct KillMatrix(pub HashMap<(Player, Player), Duel>);
+
+impl KillMatrix {
+    /// Returns the duel between two opposing players from the perspective of `player`.
+    /// The players can be given in any order.
+    pub fn get(&self, player: &Player, opponent: &Player) -> Option<Duel> {
+        if let Some(d) = self.0.get(&(player.clone(), opponent.clone())) {
+            return Some(d.clone());
+        }
+        self.0
+            .get(&(opponent.clone(), player.clone()))
+            .map(|d| Duel {
+                kills: d.deaths,
+                deaths: d.kills,
+            })
+    }
+}
+
+/// Kills between two players.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Duel {
+    /// Number of times the first player killed the second.
+    pub kills: u32,
+    /// Number of times the first player was killed by the second.
+    pub deaths: u32,
+}
+
+/// Contains the round-by-round economy of a single map. Corresponds to data found on
+/// [HLTV's economy page](https://www.hltv.org/stats/matches/economy/mapstatsid/114121/astralis-vs-vitality).
+#[derive(Debug, PartialEq)]
+pub struct EconomyPage {
+    /// ID of the map stats page this economy page belongs to.
+    pub id: u32,
+    /// Economy of both teams per round, including overtime rounds.
+    pub rounds: Vec<EconomyRound>,
+}
+
+/// Economy of both teams in a single round.
+#[derive(Debug, PartialEq, Clone)]
+pub struct EconomyRound {
+    /// Number of the round, starting at 1.
+    pub number: u32,
+    pub team1: TeamEconomy,
+    pub team2: TeamEconomy,
+}
+
+/// Economy of a single team in a round.
+#[derive(Debug, PartialEq, Clone)]
+pub struct TeamEconomy {
+    /// Total value of the team's equipment at the start of the round.
+    pub equipment_value: u32,
+    /// Category of the buy, according to HLTV.
+    pub buy_type: BuyType,
+    /// True if the team won the round.
+    pub won: bool,
+}
+
+/// Categories of buys that HLTV distinguishes.
+#[derive(Debug, PartialEq, Clone)]
+pub enum BuyType {
+    /// The buy type icon isn't recognized.
+    Unknown,
+    /// First round of a half.
+    Pistol,
+    /// Little to no equipment is bought to save money.
+    Eco,
+    /// Partial buy, spending most of the money without being able to afford a full buy.
+    ForceBuy,
+    /// Rifles and utility for the whole team.
+    FullBuy,
+}
+
+/// All CSGO maps that are listed on HLTV
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone)]
 pub enum Map {
     #[default]
     Unknown,
@@ -258,6 +1230,9 @@ pub enum Map {
     Tuscan,
     Vertigo,
     Ancient,
+    Anubis,
+    /// A map that isn't known to this crate yet, e.g. a newly added one.
+    Other(String),
 }
 
 impl From<String> for Map {
@@ -276,7 +1251,9 @@ impl From<String> for Map {
             "Tuscan" => Tuscan,
             "Vertigo" => Vertigo,
             "Ancient" => Ancient,
-            &_ => Unknown,
+            "Anubis" => Anubis,
+            "" | "TBA" | "Default" => Unknown,
+            _ => Other(s),
         }
     }
 }
@@ -297,6 +1274,8 @@ impl std::fmt::Display for Map {
             Map::Tuscan => write!(f, "de_tuscan"),
             Map::Vertigo => write!(f, "de_vertigo"),
             Map::Ancient => write!(f, "de_ancient"),
+            Map::Anubis => write!(f, "de_anubis"),
+            Map::Other(s) => write!(f, "de_{}", s.to_lowercase()),
         }
     }
 }
@@ -16,6 +16,21 @@ Currently, the following API calls are supported:
 - [`crate::upcoming`]
 - [`crate::results`]
 - [`crate::get_match`]
+- [`crate::get_map_stats`]
+- [`crate::get_performance`]
+- [`crate::get_economy`]
+- [`crate::get_team`]
+- [`crate::get_team_stats`]
+- [`crate::get_player`]
+- [`crate::get_player_stats`]
+- [`crate::transfers`]
+- [`crate::search`]
+- [`crate::players_leaderboard`]
+- [`crate::ranking`]
+- [`crate::valve_ranking`]
+- [`crate::get_event`]
+- [`crate::events`]
+- [`crate::events_archive`]
 
 ## Examples
 
@@ -68,6 +83,20 @@ pub mod request;
 pub use request::upcoming::upcoming;
 pub use request::results::results;
 pub use request::match_page::get_match;
+pub use request::map_stats::get_map_stats;
+pub use request::performance::get_performance;
+pub use request::economy::get_economy;
+pub use request::team_page::get_team;
+pub use request::team_stats::get_team_stats;
+pub use request::player_page::get_player;
+pub use request::player_stats::get_player_stats;
+pub use request::transfers::transfers;
+pub use request::search::search;
+pub use request::leaderboard::players_leaderboard;
+pub use request::ranking::ranking;
+pub use request::valve_ranking::valve_ranking;
+pub use request::event::get_event;
+pub use request::events::{events, events_archive};
 
 /// Implements a conversion from a DOM object to a collection of its own type.
 pub trait ConvertCollection
@@ -0,0 +1,16 @@
+use std::marker::PhantomData;
+
+use crate::data::EconomyPage;
+use crate::Request;
+
+/// Builds a [`Request`] object to fetch the [`EconomyPage`] of a played map.
+/// The ID is the same as the one used for [`get_map_stats`][crate::get_map_stats].
+pub fn get_economy(id: u32) -> Request<EconomyPage> {
+    Request {
+        url: format!(
+            "https://www.hltv.org/stats/matches/economy/mapstatsid/{}/xyz",
+            id
+        ),
+        _m: PhantomData,
+    }
+}
@@ -0,0 +1,14 @@
+use std::marker::PhantomData;
+
+use crate::data::EventDetails;
+use crate::Request;
+
+/// Builds a [`Request`] object to fetch the [`EventDetails`] of a given event.
+/// The ID of an event is the number you can find in the HLTV URL which should
+/// look like `hltv.org/events/{id}/...`
+pub fn get_event(id: u32) -> Request<EventDetails> {
+    Request {
+        url: format!("https://www.hltv.org/events/{}/xyz", id),
+        _m: PhantomData,
+    }
+}
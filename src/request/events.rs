@@ -0,0 +1,126 @@
+use crate::request::*;
+
+/// Request builder for event listings.
+#[derive(Default)]
+pub struct EventsRequestBuilder {
+    archive: bool,
+    from: String,
+    to: String,
+    prize_min: Option<u32>,
+    prize_max: Option<u32>,
+    teams_min: Option<u32>,
+    teams_max: Option<u32>,
+    offset: u32,
+    event_filter: EventTypeFilter,
+}
+
+/// Use this to build requests for ongoing and upcoming events.
+///
+/// # Example
+///
+/// ```rust
+/// let req = hltv::events()
+///     .prize_pool(100000, 2000000)
+///     .event_type(EventTypeFilter::Lan)
+///     .build();
+/// ```
+pub fn events() -> EventsRequestBuilder {
+    EventsRequestBuilder::default()
+}
+
+/// Use this to build requests for past events. The archive lists 50 events per page,
+/// use [`offset`][EventsRequestBuilder::offset] to fetch the following pages.
+///
+/// # Example
+///
+/// ```rust
+/// let req = hltv::events_archive()
+///     .year(2021)
+///     .teams(16, 32)
+///     .offset(50)
+///     .build();
+/// ```
+pub fn events_archive() -> EventsRequestBuilder {
+    EventsRequestBuilder {
+        archive: true,
+        ..Default::default()
+    }
+}
+
+/// Here you can find all builder methods to specify which events you want to fetch.
+impl EventsRequestBuilder {
+    /// Get events from a particular year.
+    #[must_use]
+    pub fn year(mut self, year: u32) -> Self {
+        self.from = format!("{}-01-01", year);
+        self.to = format!("{}-12-31", year);
+        self
+    }
+    /// Specify start date of events. Needs to be used with .to()
+    #[must_use]
+    pub fn from(mut self, year: u32, month: u32, day: u32) -> Self {
+        self.from = format!("{}-{:02}-{:02}", year, month, day);
+        self
+    }
+    /// Specify end date of events. Needs to be used with .from()
+    #[must_use]
+    pub fn to(mut self, year: u32, month: u32, day: u32) -> Self {
+        self.to = format!("{}-{:02}-{:02}", year, month, day);
+        self
+    }
+    /// Only select events with a prize pool (in USD) within the given range.
+    #[must_use]
+    pub fn prize_pool(mut self, min: u32, max: u32) -> Self {
+        self.prize_min = Some(min);
+        self.prize_max = Some(max);
+        self
+    }
+    /// Only select events with a number of teams within the given range.
+    #[must_use]
+    pub fn teams(mut self, min: u32, max: u32) -> Self {
+        self.teams_min = Some(min);
+        self.teams_max = Some(max);
+        self
+    }
+    /// Skip the given number of events. Used for pagination in the archive.
+    #[must_use]
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.offset = offset;
+        self
+    }
+    #[must_use]
+    pub fn event_type(mut self, event_filter: EventTypeFilter) -> Self {
+        self.event_filter = event_filter;
+        self
+    }
+    #[must_use]
+    pub fn build(self) -> Request<Vec<EventSummary>> {
+        let mut query = match self.archive {
+            true => String::from("events/archive?"),
+            false => String::from("events?"),
+        };
+        query += &format!("offset={}", self.offset);
+        if !self.from.is_empty() && !self.to.is_empty() {
+            query += &format!("&startDate={}&endDate={}", self.from, self.to);
+        }
+        if let (Some(min), Some(max)) = (self.prize_min, self.prize_max) {
+            query += &format!("&prizeMin={}&prizeMax={}", min, max);
+        }
+        if let (Some(min), Some(max)) = (self.teams_min, self.teams_max) {
+            query += &format!("&teamCountMin={}&teamCountMax={}", min, max);
+        }
+        // The event listings distinguish between several kinds of LAN events.
+        let types: &[&str] = match self.event_filter {
+            EventTypeFilter::All => &[],
+            EventTypeFilter::Lan => &["MAJOR", "INTLLAN", "REGIONALLAN", "LOCALLAN"],
+            EventTypeFilter::Online => &["ONLINE"],
+        };
+        for t in types {
+            query += &format!("&eventType={}", t);
+        }
+        Request {
+            url: format!("{}{}", HLTV_ROOT, query),
+            _m: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use crate::data::Map;
+use crate::request::*;
+
+/// Request builder for the player leaderboard.
+#[derive(Default)]
+pub struct LeaderboardRequestBuilder {
+    filter: StatsFilter,
+    min_maps: Option<u32>,
+}
+
+/// Use this to build requests for the player leaderboard, which ranks players by rating.
+///
+/// # Example
+///
+/// ```rust
+/// let req = hltv::players_leaderboard()
+///     .year(2021)
+///     .ranking(RankingFilter::Top30)
+///     .map(Map::Inferno)
+///     .min_maps(50)
+///     .event_type(EventTypeFilter::Lan)
+///     .build();
+/// ```
+pub fn players_leaderboard() -> LeaderboardRequestBuilder {
+    LeaderboardRequestBuilder::default()
+}
+
+/// Here you can find all builder methods to filter the matches that are considered
+/// for the leaderboard.
+impl LeaderboardRequestBuilder {
+    stats_filter_methods!(maps);
+    /// Only list players with at least the given number of maps played.
+    #[must_use]
+    pub fn min_maps(mut self, count: u32) -> Self {
+        self.min_maps = Some(count);
+        self
+    }
+    #[must_use]
+    pub fn build(self) -> Request<Vec<LeaderboardEntry>> {
+        let mut query = format!("stats/players?{}", self.filter.query());
+        if let Some(count) = self.min_maps {
+            query += &format!("&minMapCount={}", count);
+        }
+        Request {
+            url: format!("{}{}", HLTV_ROOT, query),
+            _m: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use std::marker::PhantomData;
+
+use crate::data::MapStatsPage;
+use crate::Request;
+
+/// Builds a [`Request`] object to fetch the [`MapStatsPage`] of a played map.
+/// The ID can be found in [`MapScore::stats_id`][crate::data::MapScore::stats_id]
+/// or in the HLTV URL, which should look like `hltv.org/stats/matches/mapstatsid/{id}/...`
+pub fn get_map_stats(id: u32) -> Request<MapStatsPage> {
+    Request {
+        url: format!("https://www.hltv.org/stats/matches/mapstatsid/{}/xyz", id),
+        _m: PhantomData,
+    }
+}
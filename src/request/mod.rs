@@ -2,11 +2,102 @@ use crate::data::*;
 use crate::Request;
 use std::marker::PhantomData;
 
+/// Generates the builder methods of [`StatsFilter`] for a request builder with a
+/// `filter` field. Use `stats_filter_methods!(maps)` to include the map filter.
+macro_rules! stats_filter_methods {
+    () => {
+        /// Only consider matches from a particular year.
+        #[must_use]
+        pub fn year(mut self, year: u32) -> Self {
+            self.filter.from = format!("{}-01-01", year);
+            self.filter.to = format!("{}-12-31", year);
+            self
+        }
+        /// Specify start date of considered matches. Needs to be used with .to()
+        #[must_use]
+        pub fn from(mut self, year: u32, month: u32, day: u32) -> Self {
+            self.filter.from = format!("{}-{:02}-{:02}", year, month, day);
+            self
+        }
+        /// Specify end date of considered matches. Needs to be used with .from()
+        #[must_use]
+        pub fn to(mut self, year: u32, month: u32, day: u32) -> Self {
+            self.filter.to = format!("{}-{:02}-{:02}", year, month, day);
+            self
+        }
+        /// Only consider matches against opponents in the given ranking range.
+        #[must_use]
+        pub fn ranking(mut self, ranking: RankingFilter) -> Self {
+            self.filter.ranking = ranking;
+            self
+        }
+        #[must_use]
+        pub fn event_type(mut self, event_filter: EventTypeFilter) -> Self {
+            self.filter.match_filter = event_filter;
+            self
+        }
+    };
+    (maps) => {
+        stats_filter_methods!();
+        /// Only consider the given map.
+        #[must_use]
+        pub fn map(mut self, map: Map) -> Self {
+            self.filter.maps = vec![map];
+            self
+        }
+        /// Only consider the given maps.
+        #[must_use]
+        pub fn maps(mut self, maps: Vec<Map>) -> Self {
+            self.filter.maps = maps;
+            self
+        }
+    };
+}
+
 pub mod results;
 pub mod upcoming;
 pub mod match_page;
+pub mod map_stats;
+pub mod performance;
+pub mod economy;
+pub mod team_page;
+pub mod team_stats;
+pub mod player_page;
+pub mod player_stats;
+pub mod transfers;
+pub mod search;
+pub mod leaderboard;
+pub mod ranking;
+pub mod valve_ranking;
+pub mod event;
+pub mod events;
 
-const HLTV_ROOT: &str = "https://www.hltv.org/";
+pub(crate) const HLTV_ROOT: &str = "https://www.hltv.org/";
+
+/// Match filters that are shared by all stats requests.
+#[derive(Default)]
+pub(crate) struct StatsFilter {
+    from: String,
+    to: String,
+    ranking: RankingFilter,
+    maps: Vec<Map>,
+    match_filter: EventTypeFilter,
+}
+
+impl StatsFilter {
+    /// Returns the filter as query parameters, without a leading `&`.
+    pub(crate) fn query(&self) -> String {
+        let mut query = format!("matchType={}", self.match_filter);
+        query += &format!("&rankingFilter={}", self.ranking);
+        if !self.from.is_empty() && !self.to.is_empty() {
+            query += &format!("&startDate={}&endDate={}", self.from, self.to);
+        }
+        for map in self.maps.iter() {
+            query += &format!("&maps={}", map);
+        }
+        query
+    }
+}
 
 /// An event/match filter for building Requests.
 #[derive(Default)]
@@ -26,3 +117,29 @@ impl std::fmt::Display for EventTypeFilter {
         }
     }
 }
+
+/// A filter for stats requests, that only considers matches against teams
+/// in the given range of the HLTV ranking.
+#[derive(Default)]
+pub enum RankingFilter {
+    #[default]
+    All,
+    Top5,
+    Top10,
+    Top20,
+    Top30,
+    Top50,
+}
+
+impl std::fmt::Display for RankingFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RankingFilter::All => write!(f, "All"),
+            RankingFilter::Top5 => write!(f, "Top5"),
+            RankingFilter::Top10 => write!(f, "Top10"),
+            RankingFilter::Top20 => write!(f, "Top20"),
+            RankingFilter::Top30 => write!(f, "Top30"),
+            RankingFilter::Top50 => write!(f, "Top50"),
+        }
+    }
+}
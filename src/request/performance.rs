@@ -0,0 +1,17 @@
+use std::marker::PhantomData;
+
+use crate::data::PerformancePage;
+use crate::Request;
+
+/// Builds a [`Request`] object to fetch the [`PerformancePage`] of a played map,
+/// which contains the kill matrices between all players. The ID is the same as
+/// the one used for [`get_map_stats`][crate::get_map_stats].
+pub fn get_performance(id: u32) -> Request<PerformancePage> {
+    Request {
+        url: format!(
+            "https://www.hltv.org/stats/matches/performance/mapstatsid/{}/xyz",
+            id
+        ),
+        _m: PhantomData,
+    }
+}
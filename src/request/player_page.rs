@@ -0,0 +1,14 @@
+use std::marker::PhantomData;
+
+use crate::data::PlayerPage;
+use crate::Request;
+
+/// Builds a [`Request`] object to fetch the [`PlayerPage`] of a given player.
+/// The ID of a player is the number you can find in the HLTV URL which should
+/// look like `hltv.org/player/{id}/...`
+pub fn get_player(id: u32) -> Request<PlayerPage> {
+    Request {
+        url: format!("https://www.hltv.org/player/{}/xyz", id),
+        _m: PhantomData,
+    }
+}
@@ -0,0 +1,74 @@
+use std::collections::HashMap;
+
+use crate::data::Map;
+use crate::request::*;
+use crate::Error;
+
+/// Request builder for player statistics.
+#[derive(Default)]
+pub struct PlayerStatsRequestBuilder {
+    id: u32,
+    filter: StatsFilter,
+}
+
+/// Use this to build requests for the statistics of a player. The ID of a player
+/// is the number you can find in the HLTV URL which should look like `hltv.org/player/{id}/...`
+///
+/// # Example
+///
+/// ```rust
+/// let req = hltv::get_player_stats(7998)
+///     .year(2021)
+///     .ranking(RankingFilter::Top20)
+///     .maps(vec![Map::Inferno, Map::Nuke])
+///     .event_type(EventTypeFilter::Lan)
+///     .build();
+/// ```
+pub fn get_player_stats(id: u32) -> PlayerStatsRequestBuilder {
+    PlayerStatsRequestBuilder {
+        id,
+        ..Default::default()
+    }
+}
+
+/// Here you can find all builder methods to filter the matches that are considered
+/// for the player statistics.
+impl PlayerStatsRequestBuilder {
+    stats_filter_methods!(maps);
+    #[must_use]
+    pub fn build(self) -> PlayerStatsRequest {
+        let query = self.filter.query();
+        let url = |page: &str| {
+            format!(
+                "{}stats/players/{}{}/xyz?{}",
+                HLTV_ROOT, page, self.id, query
+            )
+        };
+        PlayerStatsRequest {
+            overview: Request::new(url("")),
+            career: Request::new(url("career/")),
+            maps: Request::new(url("maps/")),
+        }
+    }
+}
+
+/// HLTV splits the statistics of a player across several subpages. Unlike a plain
+/// [`Request`], this fetches the overview, career and maps subpages and merges them
+/// into one [`PlayerStats`].
+#[derive(Debug)]
+pub struct PlayerStatsRequest {
+    overview: Request<PlayerStats>,
+    career: Request<Vec<PlayerYearStats>>,
+    maps: Request<HashMap<Map, PlayerMapStats>>,
+}
+
+impl PlayerStatsRequest {
+    /// Fetches all subpages and converts them into [`PlayerStats`]. Returns an error
+    /// if any of the subpages is not reachable.
+    pub async fn fetch(&self) -> Result<PlayerStats, Error> {
+        let mut result = self.overview.fetch().await?;
+        result.years = self.career.fetch().await?;
+        result.maps = self.maps.fetch().await?;
+        Ok(result)
+    }
+}
@@ -0,0 +1,61 @@
+use chrono::NaiveDate;
+
+use crate::request::*;
+
+/// Request builder for the HLTV team ranking.
+#[derive(Default)]
+pub struct RankingRequestBuilder {
+    date: Option<NaiveDate>,
+    country: Option<String>,
+}
+
+/// Use this to build requests for the weekly HLTV team ranking. Without a date,
+/// the most recent ranking is fetched.
+///
+/// # Example
+///
+/// ```rust
+/// let req = hltv::ranking()
+///     .date(chrono::NaiveDate::from_ymd_opt(2022, 6, 27).unwrap())
+///     .country("Denmark")
+///     .build();
+/// ```
+pub fn ranking() -> RankingRequestBuilder {
+    RankingRequestBuilder::default()
+}
+
+/// Here you can find all builder methods to specify which ranking you want to fetch.
+impl RankingRequestBuilder {
+    /// Select the ranking published on the given date. HLTV publishes the ranking
+    /// every Monday, other dates don't return any teams.
+    #[must_use]
+    pub fn date(mut self, date: NaiveDate) -> Self {
+        self.date = Some(date);
+        self
+    }
+    /// Only list teams from the given country, e.g. `Denmark`.
+    #[must_use]
+    pub fn country(mut self, country: &str) -> Self {
+        self.country = Some(country.to_string());
+        self
+    }
+    #[must_use]
+    pub fn build(self) -> Request<Vec<RankingEntry>> {
+        let mut query = String::from("ranking/teams");
+        if let Some(date) = self.date {
+            query += &format!("/{}", date_path(date));
+        }
+        if let Some(country) = self.country {
+            query += &format!("/country/{}", country.replace(' ', "%20"));
+        }
+        Request {
+            url: format!("{}{}", HLTV_ROOT, query),
+            _m: PhantomData,
+        }
+    }
+}
+
+/// HLTV uses lowercase month names in ranking URLs, e.g. `2022/june/27`.
+pub(super) fn date_path(date: NaiveDate) -> String {
+    date.format("%Y/%B/%-d").to_string().to_lowercase()
+}
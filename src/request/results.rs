@@ -41,7 +41,6 @@ pub struct ResultsRequestBuilder {
 ///     .players(vec![7998, 7167])
 ///     .event_type(EventTypeFilter::Lan)
 ///     .build();
-
 /// ```
 pub fn results() -> ResultsRequestBuilder {
     ResultsRequestBuilder::default()
@@ -0,0 +1,22 @@
+use std::marker::PhantomData;
+
+use crate::data::SearchResults;
+use crate::Request;
+
+/// Builds a [`Request`] object to search HLTV for teams, players and events matching
+/// the given text. Use this to find out the IDs needed by the other requests.
+///
+/// # Example
+///
+/// ```rust
+/// let hits = hltv::search("natus vincere").fetch().await?;
+/// let req = hltv::results().team(hits.teams[0].id).build();
+/// ```
+pub fn search(term: &str) -> Request<SearchResults> {
+    let url = reqwest::Url::parse_with_params("https://www.hltv.org/search", &[("query", term)])
+        .expect("search URL is valid");
+    Request {
+        url: url.to_string(),
+        _m: PhantomData,
+    }
+}
@@ -0,0 +1,14 @@
+use std::marker::PhantomData;
+
+use crate::data::TeamPage;
+use crate::Request;
+
+/// Builds a [`Request`] object to fetch the [`TeamPage`] of a given team.
+/// The ID of a team is the number you can find in the HLTV URL which should
+/// look like `hltv.org/team/{id}/...`
+pub fn get_team(id: u32) -> Request<TeamPage> {
+    Request {
+        url: format!("https://www.hltv.org/team/{}/xyz", id),
+        _m: PhantomData,
+    }
+}
@@ -0,0 +1,42 @@
+use crate::request::*;
+
+/// Request builder for team statistics.
+#[derive(Default)]
+pub struct TeamStatsRequestBuilder {
+    id: u32,
+    filter: StatsFilter,
+}
+
+/// Use this to build requests for the map statistics of a team. The ID of a team
+/// is the number you can find in the HLTV URL which should look like `hltv.org/team/{id}/...`
+///
+/// # Example
+///
+/// ```rust
+/// let req = hltv::get_team_stats(4608)
+///     .from(2022, 1, 1)
+///     .to(2022, 6, 30)
+///     .ranking(RankingFilter::Top10)
+///     .event_type(EventTypeFilter::Lan)
+///     .build();
+/// ```
+pub fn get_team_stats(id: u32) -> TeamStatsRequestBuilder {
+    TeamStatsRequestBuilder {
+        id,
+        ..Default::default()
+    }
+}
+
+/// Here you can find all builder methods to filter the matches that are considered
+/// for the team statistics.
+impl TeamStatsRequestBuilder {
+    stats_filter_methods!();
+    #[must_use]
+    pub fn build(self) -> Request<TeamStats> {
+        let query = format!("stats/teams/maps/{}/xyz?{}", self.id, self.filter.query());
+        Request {
+            url: format!("{}{}", HLTV_ROOT, query),
+            _m: PhantomData,
+        }
+    }
+}
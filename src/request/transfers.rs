@@ -0,0 +1,13 @@
+use std::marker::PhantomData;
+
+use crate::data::Transfer;
+use crate::Request;
+
+/// Builds a [`Request`] object to fetch the most recent roster moves from
+/// [HLTV's transfer page](https://www.hltv.org/transfers).
+pub fn transfers() -> Request<Vec<Transfer>> {
+    Request {
+        url: "https://www.hltv.org/transfers".to_string(),
+        _m: PhantomData,
+    }
+}
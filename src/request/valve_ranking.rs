@@ -0,0 +1,56 @@
+use chrono::NaiveDate;
+
+use crate::request::ranking::date_path;
+use crate::request::*;
+
+/// Request builder for the Valve regional standings.
+#[derive(Default)]
+pub struct ValveRankingRequestBuilder {
+    date: Option<NaiveDate>,
+    region: ValveRegion,
+}
+
+/// Use this to build requests for Valve's regional standings, which decide tournament
+/// invites. Without a date, the most recent standings are fetched.
+///
+/// # Example
+///
+/// ```rust
+/// let req = hltv::valve_ranking()
+///     .date(chrono::NaiveDate::from_ymd_opt(2024, 6, 3).unwrap())
+///     .region(hltv::data::ValveRegion::Europe)
+///     .build();
+/// ```
+pub fn valve_ranking() -> ValveRankingRequestBuilder {
+    ValveRankingRequestBuilder::default()
+}
+
+/// Here you can find all builder methods to specify which standings you want to fetch.
+impl ValveRankingRequestBuilder {
+    /// Select the standings published on the given date.
+    #[must_use]
+    pub fn date(mut self, date: NaiveDate) -> Self {
+        self.date = Some(date);
+        self
+    }
+    /// Only list teams of the given region.
+    #[must_use]
+    pub fn region(mut self, region: ValveRegion) -> Self {
+        self.region = region;
+        self
+    }
+    #[must_use]
+    pub fn build(self) -> Request<Vec<ValveStanding>> {
+        let mut query = String::from("valve-ranking/teams");
+        if let Some(date) = self.date {
+            query += &format!("/{}", date_path(date));
+        }
+        if self.region != ValveRegion::Global {
+            query += &format!("/region/{}", self.region);
+        }
+        Request {
+            url: format!("{}{}", HLTV_ROOT, query),
+            _m: PhantomData,
+        }
+    }
+}
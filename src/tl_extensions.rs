@@ -103,14 +103,23 @@ impl<'a> RichNode<'a> {
         RichNode{d: self.d, n}
     }
 
+    /// Returns all children that fulfil the given predicate, in document order
+    pub fn find_all_where(self, f: impl Fn(RichNode<'a>) -> bool) -> Vec<RichNode<'a>> {
+        let mut result = Vec::new();
+        if self.n.is_some() {
+            dfs_all_where(self, &f, &mut result);
+        }
+        result
+    }
+
     /// Returns a child with given class.
     pub fn find(self, class: &'a str) -> RichNode<'a> {
-        self.find_where(&cmp_class(class))
+        self.find_where(cmp_class(class))
     }
 
     /// Returns the first child with given HTML tag
     pub fn find_tag(self, tag: &'a str) -> RichNode<'a> {
-        self.find_where(&cmp_tag(tag))
+        self.find_where(cmp_tag(tag))
     }
     /// Returns all children with given class
     pub fn find_all(self, class: &str) -> Vec<RichNode<'a>> {
@@ -257,6 +266,24 @@ fn dfs_first_where<'a>(h: RichNode<'a>, f: &dyn Fn(RichNode<'a>)->bool) -> Optio
     None
 }
 
+/// Populate a vector with all nodes of the subtree that fulfil the given
+/// predicate. Traverses the subtree via depth-first search.
+fn dfs_all_where<'a>(h: RichNode<'a>, f: &dyn Fn(RichNode<'a>)->bool, result: &mut Vec<RichNode<'a>>) {
+    if f(h) {
+        result.push(h);
+    }
+    // return when no children
+    let children = match h.get().and_then(|n| n.children()) {
+        Some(x) => x,
+        None => return,
+    };
+
+    // otherwise, iterate over all children
+    for &c in children.top().iter() {
+        dfs_all_where(c.to_rich(h.d), f, result);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,7 +1,8 @@
+use chrono::NaiveDate;
 use std::error::Error;
 use std::time::Duration;
-use hltv::request::EventTypeFilter;
-use hltv::data::Map;
+use hltv::request::{EventTypeFilter, RankingFilter};
+use hltv::data::{Map, ValveRegion};
 
 async fn wait() {
     tokio::time::sleep(Duration::from_millis(1500)).await;
@@ -42,3 +43,155 @@ async fn results() -> Result<(), Box<dyn Error>> {
     req.fetch().await?;
     Ok(())
 }
+
+/// Testing if map stats pages are parsed without throwing errors
+#[tokio::test]
+async fn get_map_stats() -> Result<(), Box<dyn Error>> {
+    wait().await;
+    let req = hltv::get_map_stats(114121);
+    req.fetch().await?;
+    Ok(())
+}
+
+/// Testing if kill matrices are parsed without throwing errors
+#[tokio::test]
+async fn get_performance() -> Result<(), Box<dyn Error>> {
+    wait().await;
+    let req = hltv::get_performance(114121);
+    req.fetch().await?;
+    Ok(())
+}
+
+/// Testing if economy pages are parsed without throwing errors
+#[tokio::test]
+async fn get_economy() -> Result<(), Box<dyn Error>> {
+    wait().await;
+    let req = hltv::get_economy(114121);
+    req.fetch().await?;
+    Ok(())
+}
+
+/// Testing if team pages are parsed without throwing errors
+#[tokio::test]
+async fn get_team() -> Result<(), Box<dyn Error>> {
+    wait().await;
+    let team = hltv::get_team(4608).fetch().await?;
+    assert_eq!(team.id, 4608);
+    Ok(())
+}
+
+/// Testing if team stats are parsed without throwing errors
+#[tokio::test]
+async fn get_team_stats() -> Result<(), Box<dyn Error>> {
+    wait().await;
+    let req = hltv::get_team_stats(4608)
+        .year(2022)
+        .ranking(RankingFilter::Top10)
+        .event_type(EventTypeFilter::Lan)
+        .build();
+    req.fetch().await?;
+    Ok(())
+}
+
+/// Testing if player pages are parsed without throwing errors
+#[tokio::test]
+async fn get_player() -> Result<(), Box<dyn Error>> {
+    wait().await;
+    let player = hltv::get_player(7998).fetch().await?;
+    assert_eq!(player.id, 7998);
+    Ok(())
+}
+
+/// Testing if player stats are parsed without throwing errors
+#[tokio::test]
+async fn get_player_stats() -> Result<(), Box<dyn Error>> {
+    wait().await;
+    let req = hltv::get_player_stats(7998)
+        .year(2021)
+        .ranking(RankingFilter::Top20)
+        .build();
+    req.fetch().await?;
+    Ok(())
+}
+
+/// Testing if the transfer listing is parsed without throwing errors
+#[tokio::test]
+async fn transfers() -> Result<(), Box<dyn Error>> {
+    wait().await;
+    let req = hltv::transfers();
+    req.fetch().await?;
+    Ok(())
+}
+
+/// Testing if search results are parsed without throwing errors
+#[tokio::test]
+async fn search() -> Result<(), Box<dyn Error>> {
+    wait().await;
+    let req = hltv::search("natus vincere");
+    req.fetch().await?;
+    Ok(())
+}
+
+/// Testing if the player leaderboard is parsed without throwing errors
+#[tokio::test]
+async fn players_leaderboard() -> Result<(), Box<dyn Error>> {
+    wait().await;
+    let req = hltv::players_leaderboard()
+        .year(2021)
+        .ranking(RankingFilter::Top30)
+        .min_maps(50)
+        .build();
+    req.fetch().await?;
+    Ok(())
+}
+
+/// Testing if the team ranking is parsed without throwing errors
+#[tokio::test]
+async fn ranking() -> Result<(), Box<dyn Error>> {
+    wait().await;
+    let req = hltv::ranking()
+        .date(NaiveDate::from_ymd_opt(2022, 6, 27).unwrap())
+        .build();
+    req.fetch().await?;
+    Ok(())
+}
+
+/// Testing if the Valve standings are parsed without throwing errors
+#[tokio::test]
+async fn valve_ranking() -> Result<(), Box<dyn Error>> {
+    wait().await;
+    let req = hltv::valve_ranking().region(ValveRegion::Europe).build();
+    req.fetch().await?;
+    Ok(())
+}
+
+/// Testing if event pages are parsed without throwing errors
+#[tokio::test]
+async fn get_event() -> Result<(), Box<dyn Error>> {
+    wait().await;
+    let event = hltv::get_event(6372).fetch().await?;
+    assert_eq!(event.id, 6372);
+    Ok(())
+}
+
+/// Testing if upcoming events are parsed without throwing errors
+#[tokio::test]
+async fn events() -> Result<(), Box<dyn Error>> {
+    wait().await;
+    let req = hltv::events().event_type(EventTypeFilter::Lan).build();
+    req.fetch().await?;
+    Ok(())
+}
+
+/// Testing if the event archive is parsed without throwing errors
+#[tokio::test]
+async fn events_archive() -> Result<(), Box<dyn Error>> {
+    wait().await;
+    let req = hltv::events_archive()
+        .year(2021)
+        .prize_pool(100000, 2000000)
+        .offset(50)
+        .build();
+    req.fetch().await?;
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use chrono::{DateTime, NaiveDateTime, Utc};
+use chrono::{TimeZone, Utc};
 use hltv::data::*;
 use pretty_assertions::assert_eq;
 use std::error::Error;
@@ -25,6 +25,18 @@ fn perf(id: u32, s: (u32, u32, f32, f32, f32), name: &str) -> Performance {
     )
 }
 
+/// Convenient constructor for LineupPlayer
+fn lp(id: u32, name: &str, country: &str) -> LineupPlayer {
+    LineupPlayer {
+        player: Player {
+            id,
+            nickname: name.to_string(),
+        },
+        country: Some(country.to_string()),
+        stand_in: false,
+    }
+}
+
 /// Ad-hoc testing method for LIVE matches.
 #[tokio::test]
 async fn ad_hoc() -> Result<(), Box<dyn Error>> {
@@ -50,7 +62,34 @@ async fn alt_logo() -> Result<(), Box<dyn Error>> {
 async fn concluded_bo3() -> Result<(), Box<dyn Error>> {
     wait().await;
     // Bo3 with one 6 man Team
-    let res = hltv::get_match(2346065).fetch().await?;
+    let mut res = hltv::get_match(2346065).fetch().await?;
+    // HLTV lists the lineups in no particular order
+    res.lineups
+        .iter_mut()
+        .for_each(|l| l.players.sort_by_key(|p| p.player.id));
+    // Map stats IDs are handed out in the order the maps are played
+    let stats_ids: Vec<u32> = res.maps.iter().filter_map(|m| m.stats_id).collect();
+    assert_eq!(stats_ids.len(), 3);
+    assert!(stats_ids.windows(2).all(|w| w[0] < w[1]));
+    res.maps.iter_mut().for_each(|m| m.stats_id = None);
+    // The head-to-head box, past matches, media and odds are updated by HLTV over
+    // time, so they are checked for consistency instead of exact values.
+    let h2h = res.head_to_head.take().expect("no head-to-head box");
+    assert!(h2h.meetings.iter().all(|m| m.map != Map::Unknown));
+    assert!(h2h.team1_wins + h2h.team2_wins >= h2h.meetings.len() as u32);
+    let past_matches = std::mem::take(&mut res.past_matches);
+    assert_eq!(
+        past_matches.iter().map(|p| p.team.clone()).collect::<Vec<_>>(),
+        vec![WhichTeam::First, WhichTeam::Second]
+    );
+    assert!(past_matches.iter().all(|p| p.matches.iter().all(|m| m.id != 2346065)));
+    let media = std::mem::take(&mut res.media);
+    assert!(media
+        .demos
+        .iter()
+        .all(|d| d.url.starts_with("https://www.hltv.org/download/demo/")));
+    let odds = std::mem::take(&mut res.odds);
+    assert!(odds.iter().all(|o| o.team1 > 1.0 && o.team2 > 1.0));
     assert_eq!(
         res,
         MatchPage {
@@ -62,7 +101,7 @@ async fn concluded_bo3() -> Result<(), Box<dyn Error>> {
                 id: 5206,
                 name: "BLAST Premier Global Final 2020".to_string()
             },
-            date: DateTime::<Utc>::from_utc(NaiveDateTime::from_timestamp(1611415800, 0), Utc),
+            date: Utc.timestamp_opt(1611415800, 0).unwrap(),
             format: MatchFormat::Bo3,
             maps: vec![
                 MapScore::new(Map::Dust2, 16, 14),
@@ -70,6 +109,7 @@ async fn concluded_bo3() -> Result<(), Box<dyn Error>> {
                 MapScore::new(Map::Inferno, 16, 5),
             ],
             score: Some(MatchScore { team1: 2, team2: 1 }),
+            round_score: None,
             stats: vec![
                 perf(7398, (67, 53, 87.3, 71.4, 1.25), "dupreeh"),
                 perf(7592, (56, 51, 79.0, 68.8, 1.13), "device"),
@@ -83,6 +123,34 @@ async fn concluded_bo3() -> Result<(), Box<dyn Error>> {
                 perf(19512, (24, 42, 59.6, 60.8, 0.76), "Nivera"),
                 perf(14176, (25, 37, 47.8, 70.2, 0.75), "misutaaa"),
             ],
+            lineups: vec![
+                Lineup {
+                    team: WhichTeam::First,
+                    players: vec![
+                        lp(4954, "Xyp9x", "Denmark"),
+                        lp(7398, "dupreeh", "Denmark"),
+                        lp(7412, "gla1ve", "Denmark"),
+                        lp(7592, "device", "Denmark"),
+                        lp(9032, "Magisk", "Denmark"),
+                    ],
+                },
+                Lineup {
+                    team: WhichTeam::Second,
+                    players: vec![
+                        lp(1225, "shox", "France"),
+                        lp(7169, "RpK", "France"),
+                        lp(7322, "apEX", "France"),
+                        lp(11893, "ZywOo", "France"),
+                        lp(14176, "misutaaa", "France"),
+                        lp(19512, "Nivera", "Belgium"),
+                    ],
+                },
+            ],
+            // checked above
+            media: MatchMedia::default(),
+            head_to_head: None,
+            past_matches: vec![],
+            odds: vec![],
         }
     );
 